@@ -1,28 +1,32 @@
 //! Rust bindings for the Slang shader language compiler
 
 pub mod reflection;
+mod spirv;
 
 #[cfg(test)]
 mod tests;
 
-use std::ffi::{CStr, CString};
+use std::ffi::{CStr, CString, c_void};
 use std::marker::PhantomData;
 use std::ptr::{null, null_mut};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 pub(crate) use shader_slang_sys as sys;
 
 pub use sys::{
-	SlangBindingType as BindingType, SlangCompileTarget as CompileTarget,
-	SlangDebugInfoLevel as DebugInfoLevel, SlangDeclKind as DeclKind,
-	SlangFloatingPointMode as FloatingPointMode, SlangImageFormat as ImageFormat,
-	SlangLayoutRules as LayoutRules, SlangLineDirectiveMode as LineDirectiveMode,
-	SlangMatrixLayoutMode as MatrixLayoutMode, SlangModifierID as ModifierID,
-	SlangOptimizationLevel as OptimizationLevel, SlangParameterCategory as ParameterCategory,
+	SlangArchiveType as ArchiveType, SlangBindingType as BindingType,
+	SlangCompileTarget as CompileTarget, SlangDebugInfoLevel as DebugInfoLevel,
+	SlangDeclKind as DeclKind, SlangFloatingPointMode as FloatingPointMode,
+	SlangImageFormat as ImageFormat, SlangLayoutRules as LayoutRules,
+	SlangLineDirectiveMode as LineDirectiveMode, SlangMatrixLayoutMode as MatrixLayoutMode,
+	SlangModifierID as ModifierID, SlangOptimizationLevel as OptimizationLevel,
+	SlangParameterCategory as ParameterCategory, SlangPathType as PathType,
 	SlangReflectionGenericArg as GenericArg, SlangReflectionGenericArgType as GenericArgType,
 	SlangResourceAccess as ResourceAccess, SlangResourceShape as ResourceShape,
 	SlangScalarType as ScalarType, SlangSourceLanguage as SourceLanguage, SlangStage as Stage,
 	SlangTypeKind as TypeKind, SlangUUID as UUID, slang_CompilerOptionName as CompilerOptionName,
-	slang_Modifier as Modifier,
+	slang_Modifier as Modifier, slang_SessionFlags as SessionFlags,
 };
 
 macro_rules! vcall {
@@ -40,9 +44,20 @@ const fn uuid(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> UUID {
 	}
 }
 
+fn uuid_eq(a: &UUID, b: &UUID) -> bool {
+	a.data1 == b.data1 && a.data2 == b.data2 && a.data3 == b.data3 && a.data4 == b.data4
+}
+
 pub enum Error {
 	Code(sys::SlangResult),
 	Blob(Blob),
+	Io(std::io::Error),
+	Message(String),
+	/// `source` annotated with where it happened, see [`ResultExt::context`].
+	Context {
+		message: String,
+		source: Box<Error>,
+	},
 }
 
 impl std::fmt::Debug for Error {
@@ -50,26 +65,87 @@ impl std::fmt::Debug for Error {
 		match self {
 			Error::Code(code) => write!(f, "{}", code),
 			Error::Blob(blob) => write!(f, "{}", blob.as_str().unwrap_or_default()),
+			Error::Io(error) => write!(f, "{}", error),
+			Error::Message(message) => write!(f, "{}", message),
+			Error::Context { message, source } => write!(f, "{}: {:?}", message, source),
 		}
 	}
 }
 
 impl std::fmt::Display for Error {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		std::fmt::Debug::fmt(self, f)
+		match self {
+			// The rest of the chain is reachable through `source`.
+			Error::Context { message, .. } => write!(f, "{}", message),
+			_ => std::fmt::Debug::fmt(self, f),
+		}
 	}
 }
 
 unsafe impl Send for Error {}
 unsafe impl Sync for Error {}
-impl std::error::Error for Error {}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Io(error) => Some(error),
+			Error::Context { source, .. } => Some(source.as_ref()),
+			_ => None,
+		}
+	}
+}
+
+impl From<std::io::Error> for Error {
+	fn from(error: std::io::Error) -> Self {
+		Error::Io(error)
+	}
+}
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+pub trait ResultExt<T> {
+	/// Wraps the error, if any, in [`Error::Context`] with `message`.
+	fn context(self, message: impl Into<String>) -> Result<T>;
+
+	/// Like [`ResultExt::context`], but only builds the message on error.
+	fn with_context<M: Into<String>>(self, message: impl FnOnce() -> M) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+	fn context(self, message: impl Into<String>) -> Result<T> {
+		self.with_context(|| message)
+	}
+
+	fn with_context<M: Into<String>>(self, message: impl FnOnce() -> M) -> Result<T> {
+		self.map_err(|source| Error::Context {
+			message: message().into(),
+			source: Box::new(source),
+		})
+	}
+}
+
+const OK: sys::SlangResult = 0;
+const E_FAIL: sys::SlangResult = 0x80004005u32 as _;
+const E_NO_INTERFACE: sys::SlangResult = 0x80004002u32 as _;
+const E_NOT_IMPLEMENTED: sys::SlangResult = 0x80004001u32 as _;
+const E_NOT_FOUND: sys::SlangResult = 0x82000005u32 as _;
+
 pub(crate) fn succeeded(result: sys::SlangResult) -> bool {
 	result >= 0
 }
 
+/// Borrows a C string returned by Slang, or `None` if it is null or not UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a nul-terminated string that stays valid and unmodified for
+/// `'a`, e.g. one owned by the object the result is borrowed from.
+unsafe fn str_from_ptr<'a>(ptr: *const std::ffi::c_char) -> Option<&'a str> {
+	(!ptr.is_null())
+		.then(|| unsafe { CStr::from_ptr(ptr) }.to_str().ok())
+		.flatten()
+}
+
 fn result_from_blob(code: sys::SlangResult, blob: *mut sys::slang_IBlob) -> Result<()> {
 	if code < 0 && !blob.is_null() {
 		Err(Error::Blob(Blob(IUnknown(
@@ -128,6 +204,43 @@ pub unsafe trait Downcast<T> {
 	fn downcast(&self) -> &T;
 }
 
+pub trait CompileTargetExt {
+	/// The conventional file extension for code of this target, without the leading dot.
+	fn file_extension(&self) -> Option<&'static str>;
+}
+
+impl CompileTargetExt for CompileTarget {
+	fn file_extension(&self) -> Option<&'static str> {
+		match self {
+			CompileTarget::Glsl => Some("glsl"),
+			CompileTarget::Hlsl => Some("hlsl"),
+			CompileTarget::Spirv | CompileTarget::WgslSpirv => Some("spv"),
+			CompileTarget::SpirvAsm | CompileTarget::WgslSpirvAsm => Some("spvasm"),
+			CompileTarget::Dxbc => Some("dxbc"),
+			CompileTarget::DxbcAsm => Some("dxbc.asm"),
+			CompileTarget::Dxil => Some("dxil"),
+			CompileTarget::DxilAsm => Some("dxil.asm"),
+			CompileTarget::CSource => Some("c"),
+			CompileTarget::CppSource | CompileTarget::HostCppSource => Some("cpp"),
+			CompileTarget::CudaSource => Some("cu"),
+			CompileTarget::Ptx => Some("ptx"),
+			CompileTarget::Metal => Some("metal"),
+			CompileTarget::MetalLib => Some("metallib"),
+			CompileTarget::MetalLibAsm => Some("metallib.asm"),
+			CompileTarget::Wgsl => Some("wgsl"),
+			CompileTarget::ShaderSharedLibrary | CompileTarget::HostSharedLibrary => {
+				Some(std::env::consts::DLL_EXTENSION)
+			}
+			// Executables have no extension on most platforms.
+			CompileTarget::HostExecutable => {
+				let extension = std::env::consts::EXE_EXTENSION;
+				(!extension.is_empty()).then_some(extension)
+			}
+			_ => None,
+		}
+	}
+}
+
 #[repr(transparent)]
 pub struct IUnknown(std::ptr::NonNull<std::ffi::c_void>);
 
@@ -178,8 +291,714 @@ impl Blob {
 	pub fn as_str(&self) -> std::result::Result<&str, std::str::Utf8Error> {
 		std::str::from_utf8(self.as_slice())
 	}
+
+	pub fn copy_from_slice(data: &[u8]) -> Blob {
+		BlobImpl::create(data.to_vec())
+	}
+
+	/// Wraps `data` without copying it.
+	pub fn from_static(data: &'static [u8]) -> Blob {
+		BlobImpl::create(data)
+	}
+
+	/// Takes ownership of `data` without copying it; it is freed when the last reference to the
+	/// blob is released.
+	pub fn from_owned(data: Vec<u8>) -> Blob {
+		BlobImpl::create(data)
+	}
+
+	/// Copies the contents of every blob, in order, into a new blob.
+	pub fn concat(blobs: &[&Blob]) -> Blob {
+		BlobImpl::create(
+			blobs
+				.iter()
+				.flat_map(|blob| blob.as_slice())
+				.copied()
+				.collect::<Vec<_>>(),
+		)
+	}
+
+	/// Copies the contents into an owned `Vec` and releases the blob.
+	pub fn into_vec(self) -> Vec<u8> {
+		self.as_slice().to_vec()
+	}
+
+	/// Writes the contents to `w` without copying them first and returns the number of bytes
+	/// written.
+	pub fn copy_to_writer(&self, w: &mut impl std::io::Write) -> std::io::Result<usize> {
+		w.write_all(self.as_slice())?;
+		Ok(self.len())
+	}
+}
+
+impl From<&'static [u8]> for Blob {
+	fn from(data: &'static [u8]) -> Self {
+		Blob::from_static(data)
+	}
+}
+
+impl From<Vec<u8>> for Blob {
+	fn from(data: Vec<u8>) -> Self {
+		Blob::from_owned(data)
+	}
+}
+
+impl AsRef<[u8]> for Blob {
+	fn as_ref(&self) -> &[u8] {
+		self.as_slice()
+	}
+}
+
+impl std::ops::Deref for Blob {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		self.as_slice()
+	}
+}
+
+// Implemented by Rust types exposed to Slang as reference counted COM objects through `ComObject`.
+trait ComImpl: Sized + 'static {
+	type Vtable: 'static;
+
+	fn vtable() -> &'static Self::Vtable;
+
+	// Whether the object implements the interface `uuid`, including every base interface.
+	fn supports(uuid: &UUID) -> bool;
+}
+
+// A COM object wrapping `inner`, freed when Slang and Rust release their last reference. The
+// vtable of `T` starts with `ISlangUnknown`, whose entries are the functions below.
+#[repr(C)]
+struct ComObject<T: ComImpl> {
+	vtable: &'static T::Vtable,
+	ref_count: AtomicU32,
+	inner: T,
+}
+
+impl<T: ComImpl> ComObject<T> {
+	const UNKNOWN_VTABLE: sys::ISlangUnknown__bindgen_vtable = sys::ISlangUnknown__bindgen_vtable {
+		ISlangUnknown_queryInterface: Self::query_interface,
+		ISlangUnknown_addRef: Self::add_ref,
+		ISlangUnknown_release: Self::release,
+	};
+
+	fn create(inner: T) -> IUnknown {
+		let object = Box::new(ComObject {
+			vtable: T::vtable(),
+			ref_count: AtomicU32::new(1),
+			inner,
+		});
+
+		IUnknown(std::ptr::NonNull::new(Box::into_raw(object) as *mut _).unwrap())
+	}
+
+	fn get<'a>(this: *mut c_void) -> &'a T {
+		unsafe { &(*(this as *const Self)).inner }
+	}
+
+	unsafe extern "C" fn query_interface(
+		this: *mut sys::ISlangUnknown,
+		uuid: *const UUID,
+		out_object: *mut *mut c_void,
+	) -> sys::SlangResult {
+		if T::supports(unsafe { &*uuid }) {
+			unsafe {
+				Self::add_ref(this);
+				*out_object = this as _;
+			}
+			OK
+		} else {
+			E_NO_INTERFACE
+		}
+	}
+
+	unsafe extern "C" fn add_ref(this: *mut sys::ISlangUnknown) -> u32 {
+		let this = unsafe { &*(this as *const Self) };
+		this.ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	unsafe extern "C" fn release(this: *mut sys::ISlangUnknown) -> u32 {
+		let count = unsafe { &*(this as *const Self) }
+			.ref_count
+			.fetch_sub(1, Ordering::Release)
+			- 1;
+
+		if count == 0 {
+			std::sync::atomic::fence(Ordering::Acquire);
+			drop(unsafe { Box::from_raw(this as *mut Self) });
+		}
+
+		count
+	}
+
+	// `ICastable::castAs`, which unlike `queryInterface` doesn't add a reference.
+	unsafe extern "C" fn cast_as(this: *mut c_void, guid: *const UUID) -> *mut c_void {
+		if T::supports(unsafe { &*guid }) {
+			this
+		} else {
+			null_mut()
+		}
+	}
+}
+
+// An `ISlangBlob` implemented in Rust, for passing data owned by Rust to Slang.
+struct BlobImpl {
+	data: std::borrow::Cow<'static, [u8]>,
+}
+
+static BLOB_IMPL_VTABLE: sys::IBlobVtable = sys::IBlobVtable {
+	_base: ComObject::<BlobImpl>::UNKNOWN_VTABLE,
+	getBufferPointer: BlobImpl::buffer_pointer,
+	getBufferSize: BlobImpl::buffer_size,
+};
+
+impl ComImpl for BlobImpl {
+	type Vtable = sys::IBlobVtable;
+
+	fn vtable() -> &'static Self::Vtable {
+		&BLOB_IMPL_VTABLE
+	}
+
+	fn supports(uuid: &UUID) -> bool {
+		uuid_eq(uuid, &IUnknown::IID) || uuid_eq(uuid, &Blob::IID)
+	}
+}
+
+impl BlobImpl {
+	fn create(data: impl Into<std::borrow::Cow<'static, [u8]>>) -> Blob {
+		Blob(ComObject::create(BlobImpl { data: data.into() }))
+	}
+
+	unsafe extern "C" fn buffer_pointer(this: *mut c_void) -> *const c_void {
+		ComObject::<Self>::get(this).data.as_ptr() as _
+	}
+
+	unsafe extern "C" fn buffer_size(this: *mut c_void) -> usize {
+		ComObject::<Self>::get(this).data.len()
+	}
+}
+
+pub trait FileSystem {
+	/// Loads the file at `path`. Missing files should be reported as [`std::io::ErrorKind::NotFound`]
+	/// so Slang can continue searching other locations.
+	fn load_file(&self, path: &str) -> Result<Blob>;
+}
+
+/// A file system Slang can also write its outputs into.
+pub trait MutableFileSystem: FileSystem {
+	fn save_file(&self, path: &str, data: &[u8]) -> Result<()>;
+
+	fn remove(&self, path: &str) -> Result<()>;
+
+	fn create_directory(&self, path: &str) -> Result<()>;
+}
+
+impl<T: FileSystem + ?Sized> FileSystem for Rc<T> {
+	fn load_file(&self, path: &str) -> Result<Blob> {
+		(**self).load_file(path)
+	}
+}
+
+/// Loads files from disk, with relative paths resolved against the working directory, like
+/// Slang's default file system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+	fn load_file(&self, path: &str) -> Result<Blob> {
+		Ok(Blob::from_owned(std::fs::read(path)?))
+	}
+}
+
+/// An in-memory directory of files, e.g. for resolving `import`s of generated modules. Clones
+/// share the same files, so outputs Slang writes into it as a [`MutableFileSystem`] can be read
+/// back.
+#[derive(Clone, Default)]
+pub struct VirtualDirectory {
+	files: Rc<std::cell::RefCell<std::collections::HashMap<String, Blob>>>,
+}
+
+impl VirtualDirectory {
+	pub fn new(files: std::collections::HashMap<String, Vec<u8>>) -> Self {
+		let files = files
+			.into_iter()
+			.map(|(path, content)| (normalize_path(&path), Blob::from_owned(content)))
+			.collect();
+
+		Self {
+			files: Rc::new(std::cell::RefCell::new(files)),
+		}
+	}
+
+	/// Paths of all files currently in the directory.
+	pub fn paths(&self) -> Vec<String> {
+		self.files.borrow().keys().cloned().collect()
+	}
+
+	fn get(&self, path: &str) -> Option<Blob> {
+		self.files.borrow().get(&normalize_path(path)).cloned()
+	}
+
+	fn insert(&self, path: &str, content: Vec<u8>) {
+		self.files
+			.borrow_mut()
+			.insert(normalize_path(path), Blob::from_owned(content));
+	}
+}
+
+impl From<std::collections::HashMap<String, Vec<u8>>> for VirtualDirectory {
+	fn from(files: std::collections::HashMap<String, Vec<u8>>) -> Self {
+		Self::new(files)
+	}
 }
 
+impl FileSystem for VirtualDirectory {
+	fn load_file(&self, path: &str) -> Result<Blob> {
+		self.get(path)
+			.ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound).into())
+	}
+}
+
+impl MutableFileSystem for VirtualDirectory {
+	fn save_file(&self, path: &str, data: &[u8]) -> Result<()> {
+		self.files
+			.borrow_mut()
+			.insert(normalize_path(path), Blob::copy_from_slice(data));
+		Ok(())
+	}
+
+	fn remove(&self, path: &str) -> Result<()> {
+		match self.files.borrow_mut().remove(&normalize_path(path)) {
+			Some(_) => Ok(()),
+			None => Err(std::io::Error::from(std::io::ErrorKind::NotFound).into()),
+		}
+	}
+
+	// Directories only exist implicitly as prefixes of file paths.
+	fn create_directory(&self, _path: &str) -> Result<()> {
+		Ok(())
+	}
+}
+
+// The files of `SessionDesc::virtual_files` layered over the session's file system, which
+// receives everything Slang writes.
+struct Overlay<F: ?Sized> {
+	files: VirtualDirectory,
+	base: Rc<F>,
+}
+
+impl<F: FileSystem + ?Sized> FileSystem for Overlay<F> {
+	fn load_file(&self, path: &str) -> Result<Blob> {
+		match self.files.get(path) {
+			Some(content) => Ok(content),
+			None => self.base.load_file(path),
+		}
+	}
+}
+
+impl<F: MutableFileSystem + ?Sized> MutableFileSystem for Overlay<F> {
+	fn save_file(&self, path: &str, data: &[u8]) -> Result<()> {
+		self.base.save_file(path, data)
+	}
+
+	fn remove(&self, path: &str) -> Result<()> {
+		self.base.remove(path)
+	}
+
+	fn create_directory(&self, path: &str) -> Result<()> {
+		self.base.create_directory(path)
+	}
+}
+
+fn normalize_path(path: &str) -> String {
+	let path = path.replace('\\', "/");
+	path.trim_start_matches("./").to_string()
+}
+
+const CASTABLE_IID: UUID = uuid(
+	0x87ede0e1,
+	0x4852,
+	0x44b0,
+	[0x8b, 0xf2, 0xcb, 0x31, 0x87, 0x4d, 0xe2, 0x39],
+);
+
+const FILE_SYSTEM_IID: UUID = uuid(
+	0x003a09fc,
+	0x3a4d,
+	0x4ba0,
+	[0xad, 0x60, 0x1f, 0xd8, 0x63, 0xa9, 0x15, 0xab],
+);
+
+// Exposes a `FileSystem` to Slang as an `ISlangFileSystem`.
+struct FileSystemImpl {
+	file_system: Rc<dyn FileSystem>,
+}
+
+static FILE_SYSTEM_IMPL_VTABLE: sys::IFileSystemVtable = sys::IFileSystemVtable {
+	_base: sys::ICastableVtable {
+		_base: ComObject::<FileSystemImpl>::UNKNOWN_VTABLE,
+		castAs: ComObject::<FileSystemImpl>::cast_as,
+	},
+	loadFile: FileSystemImpl::load_file,
+};
+
+impl ComImpl for FileSystemImpl {
+	type Vtable = sys::IFileSystemVtable;
+
+	fn vtable() -> &'static Self::Vtable {
+		&FILE_SYSTEM_IMPL_VTABLE
+	}
+
+	fn supports(uuid: &UUID) -> bool {
+		[IUnknown::IID, CASTABLE_IID, FILE_SYSTEM_IID]
+			.iter()
+			.any(|iid| uuid_eq(uuid, iid))
+	}
+}
+
+impl FileSystemImpl {
+	fn create(file_system: Rc<dyn FileSystem>) -> IUnknown {
+		ComObject::create(FileSystemImpl { file_system })
+	}
+
+	unsafe extern "C" fn load_file(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+		out_blob: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let this = ComObject::<Self>::get(this);
+		unsafe { load_file_into(&*this.file_system, path, out_blob) }
+	}
+}
+
+fn result_code(result: Result<()>) -> sys::SlangResult {
+	match result {
+		Ok(()) => OK,
+		Err(Error::Code(code)) => code,
+		Err(Error::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => E_NOT_FOUND,
+		Err(_) => E_FAIL,
+	}
+}
+
+unsafe fn load_file_into(
+	file_system: &dyn FileSystem,
+	path: *const std::ffi::c_char,
+	out_blob: *mut *mut sys::ISlangBlob,
+) -> sys::SlangResult {
+	let Some(path) = (unsafe { str_from_ptr(path) }) else {
+		return E_NOT_FOUND;
+	};
+
+	result_code(file_system.load_file(path).map(|blob| {
+		unsafe { *out_blob = blob.as_raw() };
+		std::mem::forget(blob);
+	}))
+}
+
+unsafe fn write_blob(out_blob: *mut *mut sys::ISlangBlob, data: &str) -> sys::SlangResult {
+	let blob = Blob::copy_from_slice(data.as_bytes());
+	unsafe { *out_blob = blob.as_raw() };
+	std::mem::forget(blob);
+	OK
+}
+
+const FILE_SYSTEM_EXT_IID: UUID = uuid(
+	0x5fb632d2,
+	0x979d,
+	0x4481,
+	[0x9f, 0xee, 0x66, 0x3c, 0x3f, 0x14, 0x49, 0xe1],
+);
+
+const MUTABLE_FILE_SYSTEM_IID: UUID = uuid(
+	0xa058675c,
+	0x1d65,
+	0x452a,
+	[0x84, 0x58, 0xcc, 0xde, 0xd1, 0x42, 0x71, 0x05],
+);
+
+// Exposes a `MutableFileSystem` to Slang as an `ISlangMutableFileSystem`.
+struct MutableFileSystemImpl {
+	file_system: Rc<dyn MutableFileSystem>,
+}
+
+static MUTABLE_FILE_SYSTEM_IMPL_VTABLE: sys::IMutableFileSystemVtable =
+	sys::IMutableFileSystemVtable {
+		_base: sys::IFileSystemExtVtable {
+			_base: sys::IFileSystemVtable {
+				_base: sys::ICastableVtable {
+					_base: ComObject::<MutableFileSystemImpl>::UNKNOWN_VTABLE,
+					castAs: ComObject::<MutableFileSystemImpl>::cast_as,
+				},
+				loadFile: MutableFileSystemImpl::load_file,
+			},
+			getFileUniqueIdentity: MutableFileSystemImpl::file_unique_identity,
+			calcCombinedPath: MutableFileSystemImpl::calc_combined_path,
+			getPathType: MutableFileSystemImpl::path_type,
+			getPath: MutableFileSystemImpl::path,
+			clearCache: MutableFileSystemImpl::clear_cache,
+			enumeratePathContents: MutableFileSystemImpl::enumerate_path_contents,
+			getOSPathKind: MutableFileSystemImpl::os_path_kind,
+		},
+		saveFile: MutableFileSystemImpl::save_file,
+		saveFileBlob: MutableFileSystemImpl::save_file_blob,
+		remove: MutableFileSystemImpl::remove,
+		createDirectory: MutableFileSystemImpl::create_directory,
+	};
+
+impl ComImpl for MutableFileSystemImpl {
+	type Vtable = sys::IMutableFileSystemVtable;
+
+	fn vtable() -> &'static Self::Vtable {
+		&MUTABLE_FILE_SYSTEM_IMPL_VTABLE
+	}
+
+	fn supports(uuid: &UUID) -> bool {
+		FileSystemImpl::supports(uuid)
+			|| uuid_eq(uuid, &FILE_SYSTEM_EXT_IID)
+			|| uuid_eq(uuid, &MUTABLE_FILE_SYSTEM_IID)
+	}
+}
+
+impl MutableFileSystemImpl {
+	fn create(file_system: Rc<dyn MutableFileSystem>) -> IUnknown {
+		ComObject::create(MutableFileSystemImpl { file_system })
+	}
+
+	fn get<'a>(this: *mut c_void) -> &'a dyn MutableFileSystem {
+		&*ComObject::<Self>::get(this).file_system
+	}
+
+	unsafe extern "C" fn load_file(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+		out_blob: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		unsafe { load_file_into(Self::get(this), path, out_blob) }
+	}
+
+	unsafe extern "C" fn file_unique_identity(
+		_this: *mut c_void,
+		path: *const std::ffi::c_char,
+		out_unique_identity: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+		unsafe { write_blob(out_unique_identity, &normalize_path(path)) }
+	}
+
+	unsafe extern "C" fn calc_combined_path(
+		_this: *mut c_void,
+		from_path_type: PathType,
+		from_path: *const std::ffi::c_char,
+		path: *const std::ffi::c_char,
+		out_path: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let (Some(from_path), Some(path)) =
+			(unsafe { (str_from_ptr(from_path), str_from_ptr(path)) })
+		else {
+			return E_NOT_FOUND;
+		};
+
+		let from_path = normalize_path(from_path);
+		let directory = match from_path_type {
+			PathType::File => from_path.rsplit_once('/').map_or("", |(dir, _)| dir),
+			PathType::Directory => &from_path,
+		};
+
+		if directory.is_empty() || directory == "." {
+			unsafe { write_blob(out_path, path) }
+		} else {
+			unsafe { write_blob(out_path, &format!("{directory}/{path}")) }
+		}
+	}
+
+	unsafe extern "C" fn path_type(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+		out_path_type: *mut PathType,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+
+		// Only files can be detected through the `MutableFileSystem` trait.
+		let result = Self::get(this).load_file(path).map(|_| ());
+		if result.is_ok() {
+			unsafe { *out_path_type = PathType::File };
+		}
+		result_code(result)
+	}
+
+	unsafe extern "C" fn path(
+		_this: *mut c_void,
+		_kind: std::ffi::c_int,
+		path: *const std::ffi::c_char,
+		out_path: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+		unsafe { write_blob(out_path, path) }
+	}
+
+	unsafe extern "C" fn clear_cache(_this: *mut c_void) {}
+
+	unsafe extern "C" fn enumerate_path_contents(
+		_this: *mut c_void,
+		_path: *const std::ffi::c_char,
+		_callback: sys::FileSystemContentsCallBack,
+		_user_data: *mut c_void,
+	) -> sys::SlangResult {
+		E_NOT_IMPLEMENTED
+	}
+
+	unsafe extern "C" fn os_path_kind(_this: *mut c_void) -> u8 {
+		// Paths don't map to the OS file system.
+		0
+	}
+
+	unsafe extern "C" fn save_file(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+		data: *const c_void,
+		size: usize,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+		let data = unsafe { std::slice::from_raw_parts(data as *const u8, size) };
+		result_code(Self::get(this).save_file(path, data))
+	}
+
+	unsafe extern "C" fn save_file_blob(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+		data_blob: *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+		let Some(data_blob) = std::ptr::NonNull::new(data_blob as *mut _) else {
+			return E_FAIL;
+		};
+
+		// The blob is borrowed from Slang, so its reference must not be released.
+		let blob = std::mem::ManuallyDrop::new(Blob(IUnknown(data_blob)));
+		result_code(Self::get(this).save_file(path, blob.as_slice()))
+	}
+
+	unsafe extern "C" fn remove(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+		result_code(Self::get(this).remove(path))
+	}
+
+	unsafe extern "C" fn create_directory(
+		this: *mut c_void,
+		path: *const std::ffi::c_char,
+	) -> sys::SlangResult {
+		let Some(path) = (unsafe { str_from_ptr(path) }) else {
+			return E_NOT_FOUND;
+		};
+		result_code(Self::get(this).create_directory(path))
+	}
+}
+
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct SharedLibrary(IUnknown);
+
+unsafe impl Interface for SharedLibrary {
+	type Vtable = sys::ISharedLibraryVtable;
+	const IID: UUID = uuid(
+		0x70dbc7c4,
+		0xdc3b,
+		0x4a07,
+		[0xae, 0x7e, 0x75, 0x2a, 0xf6, 0xa8, 0x15, 0x55],
+	);
+}
+
+impl SharedLibrary {
+	pub fn find_symbol_address_by_name(&self, name: &str) -> Option<*mut c_void> {
+		let name = CString::new(name).unwrap();
+		let ptr = vcall!(self, findSymbolAddressByName(name.as_ptr()));
+		(!ptr.is_null()).then_some(ptr)
+	}
+}
+
+/// The outputs of a compilation, exposed by Slang as an in-memory file system.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct ResultFileSystem(IUnknown);
+
+unsafe impl Interface for ResultFileSystem {
+	type Vtable = sys::IMutableFileSystemVtable;
+	const IID: UUID = MUTABLE_FILE_SYSTEM_IID;
+}
+
+impl ResultFileSystem {
+	/// Lists the files and directories directly inside `path`.
+	pub fn entries(&self, path: &str) -> Result<Vec<(PathType, String)>> {
+		unsafe extern "C" fn push_entry(
+			path_type: PathType,
+			name: *const std::ffi::c_char,
+			user_data: *mut c_void,
+		) {
+			let entries = unsafe { &mut *(user_data as *mut Vec<(PathType, String)>) };
+			if let Some(name) = unsafe { str_from_ptr(name) } {
+				entries.push((path_type, name.to_string()));
+			}
+		}
+
+		let path = CString::new(path).unwrap();
+		let mut entries = Vec::new();
+		let result = unsafe {
+			(self.vtable()._base.enumeratePathContents)(
+				self.as_raw(),
+				path.as_ptr(),
+				Some(push_entry),
+				&mut entries as *mut Vec<_> as *mut c_void,
+			)
+		};
+		result_from_blob(result, null_mut())?;
+
+		Ok(entries)
+	}
+}
+
+impl FileSystem for ResultFileSystem {
+	fn load_file(&self, path: &str) -> Result<Blob> {
+		let path = CString::new(path).unwrap();
+		let mut blob = null_mut();
+		let result = unsafe {
+			(self.vtable()._base._base.loadFile)(self.as_raw(), path.as_ptr(), &mut blob)
+		};
+		result_from_blob(result, null_mut())?;
+
+		Ok(Blob(IUnknown(
+			std::ptr::NonNull::new(blob as *mut _).unwrap(),
+		)))
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompileTimings {
+	pub total: std::time::Duration,
+	/// Time spent in downstream compilers such as dxc or glslang.
+	pub downstream: std::time::Duration,
+}
+
+/// The entry point to the Slang API, owning the core module and compiler configuration.
+///
+/// A global session is not thread-safe, so `GlobalSession` is neither `Send` nor `Sync`. To
+/// compile in parallel, create one global session per thread, for example with
+/// [`GlobalSession::scoped_compile_pool`].
 #[repr(transparent)]
 #[derive(Clone)]
 pub struct GlobalSession(IUnknown);
@@ -195,33 +1014,183 @@ unsafe impl Interface for GlobalSession {
 }
 
 impl GlobalSession {
-	pub fn new() -> Option<GlobalSession> {
+	pub fn new() -> Result<GlobalSession> {
 		let mut global_session = null_mut();
-		unsafe { sys::slang_createGlobalSession(sys::SLANG_API_VERSION as _, &mut global_session) };
-		Some(GlobalSession(IUnknown(std::ptr::NonNull::new(
-			global_session as *mut _,
-		)?)))
+		let result = unsafe {
+			sys::slang_createGlobalSession(sys::SLANG_API_VERSION as _, &mut global_session)
+		};
+		Self::from_result(result, global_session)
 	}
 
-	pub fn new_without_core_module() -> Option<GlobalSession> {
+	/// Creates a global session without Slang's core module, which almost every shader depends
+	/// on, including trivial HLSL. Load a core module saved with
+	/// [`GlobalSession::save_core_module`] through [`GlobalSession::load_core_module`], or build
+	/// one with [`GlobalSession::compile_core_module`], before compiling anything.
+	pub fn new_without_core_module() -> Result<GlobalSession> {
 		let mut global_session = null_mut();
-		unsafe {
+		let result = unsafe {
 			sys::slang_createGlobalSessionWithoutCoreModule(
 				sys::SLANG_API_VERSION as _,
 				&mut global_session,
 			)
 		};
-		Some(GlobalSession(IUnknown(std::ptr::NonNull::new(
-			global_session as *mut _,
-		)?)))
+		Self::from_result(result, global_session)
+	}
+
+	/// Calls `f` for every item on up to `threads` scoped threads, each with its own global
+	/// session, and returns the results in the order of `items`.
+	pub fn scoped_compile_pool<I: Sync, T: Send>(
+		items: &[I],
+		threads: usize,
+		f: impl Fn(&GlobalSession, &I) -> T + Sync,
+	) -> Result<Vec<T>> {
+		let chunk_size = items.len().div_ceil(threads.max(1)).max(1);
+
+		std::thread::scope(|scope| {
+			let handles = items
+				.chunks(chunk_size)
+				.map(|chunk| {
+					let f = &f;
+					scope.spawn(move || {
+						let global_session = GlobalSession::new()?;
+						Ok(chunk.iter().map(|item| f(&global_session, item)).collect())
+					})
+				})
+				.collect::<Vec<_>>();
+
+			let mut results = Vec::with_capacity(items.len());
+			for handle in handles {
+				let chunk: Result<Vec<T>> = handle.join().unwrap();
+				results.extend(chunk?);
+			}
+			Ok(results)
+		})
+	}
+
+	// Broken installs can report success without writing the out-pointer, so both are checked.
+	fn from_result(
+		result: sys::SlangResult,
+		global_session: *mut sys::slang_IGlobalSession,
+	) -> Result<GlobalSession> {
+		let global_session = std::ptr::NonNull::new(global_session as *mut _)
+			.map(|global_session| GlobalSession(IUnknown(global_session)));
+
+		match global_session {
+			_ if !succeeded(result) => Err(Error::Code(result)),
+			Some(global_session) => Ok(global_session),
+			None => Err(Error::Message(
+				"Slang reported success without creating a global session".into(),
+			)),
+		}
+	}
+
+	pub fn compile_core_module(&self) -> Result<()> {
+		result_from_blob(vcall!(self, compileCoreModule(0)), null_mut())
+	}
+
+	pub fn load_core_module(&self, core_module: &[u8]) -> Result<()> {
+		result_from_blob(
+			vcall!(
+				self,
+				loadCoreModule(core_module.as_ptr() as _, core_module.len())
+			),
+			null_mut(),
+		)
+	}
+
+	pub fn save_core_module(&self, archive_type: ArchiveType) -> Result<Blob> {
+		let mut blob = null_mut();
+		result_from_blob(
+			vcall!(self, saveCoreModule(archive_type, &mut blob)),
+			null_mut(),
+		)?;
+
+		Ok(Blob(IUnknown(
+			std::ptr::NonNull::new(blob as *mut _).unwrap(),
+		)))
+	}
+
+	/// Returns the time spent compiling so far, accumulated over every session created from this
+	/// global session.
+	pub fn compile_timings(&self) -> CompileTimings {
+		let mut total = 0.0;
+		let mut downstream = 0.0;
+		vcall!(self, getCompilerElapsedTime(&mut total, &mut downstream));
+
+		CompileTimings {
+			total: std::time::Duration::from_secs_f64(total.max(0.0)),
+			downstream: std::time::Duration::from_secs_f64(downstream.max(0.0)),
+		}
+	}
+
+	pub fn create_session(&self, desc: &SessionDesc) -> Option<Session> {
+		let mut session = null_mut();
+		let mut raw_desc = **desc;
+
+		// Slang takes its own reference, ours is released once the session is created.
+		let file_system = match (
+			&desc.mutable_file_system,
+			&desc.file_system,
+			&desc.virtual_files,
+		) {
+			(Some(base), _, Some(files)) => Some(MutableFileSystemImpl::create(Rc::new(Overlay {
+				files: files.clone(),
+				base: base.clone(),
+			}))),
+			(Some(file_system), _, None) => {
+				Some(MutableFileSystemImpl::create(file_system.clone()))
+			}
+			(None, Some(base), Some(files)) => Some(FileSystemImpl::create(Rc::new(Overlay {
+				files: files.clone(),
+				base: base.clone(),
+			}))),
+			(None, Some(file_system), None) => Some(FileSystemImpl::create(file_system.clone())),
+			// Slang's default file system can't be wrapped, so an equivalent one is used instead.
+			(None, None, Some(files)) => Some(FileSystemImpl::create(Rc::new(Overlay {
+				files: files.clone(),
+				base: Rc::new(OsFileSystem),
+			}))),
+			(None, None, None) => None,
+		};
+		if let Some(file_system) = &file_system {
+			raw_desc.fileSystem = unsafe { file_system.as_raw() };
+		}
+
+		vcall!(self, createSession(&raw_desc, &mut session));
+		Some(Session(
+			IUnknown(std::ptr::NonNull::new(session as *mut _)?),
+			desc.targetCount as _,
+		))
 	}
 
-	pub fn create_session(&self, desc: &SessionDesc) -> Option<Session> {
-		let mut session = null_mut();
-		vcall!(self, createSession(&**desc, &mut session));
-		Some(Session(IUnknown(std::ptr::NonNull::new(
-			session as *mut _,
-		)?)))
+	pub fn create_session_from_config(&self, config: &SessionConfig) -> Option<Session> {
+		let targets: Vec<_> = config
+			.targets
+			.iter()
+			.map(|target| {
+				TargetDesc::default()
+					.format(target.format)
+					.profile(self.find_profile(&target.profile))
+			})
+			.collect();
+
+		let search_paths: Vec<_> = config
+			.search_paths
+			.iter()
+			.map(|path| CString::new(path.as_str()).unwrap())
+			.collect();
+		let search_path_ptrs: Vec<_> = search_paths.iter().map(|path| path.as_ptr()).collect();
+
+		let mut options = CompilerOptions::default().defines_iter(config.defines.iter().cloned());
+		if let Some(level) = config.optimization {
+			options = options.optimization(level);
+		}
+
+		let desc = SessionDesc::default()
+			.targets(&targets)
+			.search_paths(&search_path_ptrs)
+			.options(&options);
+		self.create_session(&desc)
 	}
 
 	pub fn find_profile(&self, name: &str) -> ProfileID {
@@ -240,9 +1209,17 @@ impl GlobalSession {
 	}
 }
 
-#[repr(transparent)]
+/// A compilation scope with a fixed set of targets, search paths and compiler options.
+///
+/// Cloning a `Session` adds a reference to the same underlying Slang session rather than creating
+/// a new one, so modules loaded through one handle are visible through all of them. Slang sessions
+/// must not be used from multiple threads, which is why `Session` is neither `Send` nor `Sync`;
+/// create a separate session on each thread instead.
+// The COM pointer must stay the first field, `Interface::as_raw` reads it from there. The target
+// count is recorded when the session is created, as Slang can't be queried for it.
+#[repr(C)]
 #[derive(Clone)]
-pub struct Session(IUnknown);
+pub struct Session(IUnknown, u32);
 
 unsafe impl Interface for Session {
 	type Vtable = sys::ISessionVtable;
@@ -255,21 +1232,31 @@ unsafe impl Interface for Session {
 }
 
 impl Session {
+	/// Number of targets the session was created with, i.e. the valid `target` indices.
+	pub fn target_count(&self) -> u32 {
+		self.1
+	}
+
 	pub fn load_module(&self, name: &str) -> Result<Module> {
+		self.load_module_with_diagnostics(name)
+			.map(|(module, _)| module)
+	}
+
+	/// Like [`Session::load_module`], but also returns the diagnostics (such as warnings)
+	/// Slang produced when loading succeeded.
+	pub fn load_module_with_diagnostics(&self, name: &str) -> Result<(Module, Option<Blob>)> {
 		let name = CString::new(name).unwrap();
 		let mut diagnostics = null_mut();
 
 		let module = vcall!(self, loadModule(name.as_ptr(), &mut diagnostics));
+		let diagnostics = std::ptr::NonNull::new(diagnostics as *mut _).map(|d| Blob(IUnknown(d)));
 
 		if module.is_null() {
-			let blob = Blob(IUnknown(
-				std::ptr::NonNull::new(diagnostics as *mut _).unwrap(),
-			));
-			Err(Error::Blob(blob))
+			Err(Error::Blob(diagnostics.unwrap()))
 		} else {
 			let module = Module(IUnknown(std::ptr::NonNull::new(module as *mut _).unwrap()));
 			unsafe { (module.as_unknown().vtable().ISlangUnknown_addRef)(module.as_raw()) };
-			Ok(module)
+			Ok((module, diagnostics))
 		}
 	}
 
@@ -279,6 +1266,18 @@ impl Session {
 		path: &str,
 		source: &str,
 	) -> Result<Module> {
+		self.load_module_from_source_with_diagnostics(module_name, path, source)
+			.map(|(module, _)| module)
+	}
+
+	/// Like [`Session::load_module_from_source_string`], but also returns the diagnostics
+	/// (such as warnings) Slang produced when loading succeeded.
+	pub fn load_module_from_source_with_diagnostics(
+		&self,
+		module_name: &str,
+		path: &str,
+		source: &str,
+	) -> Result<(Module, Option<Blob>)> {
 		let module_name = CString::new(module_name).unwrap();
 		let path = CString::new(path).unwrap();
 		let source = CString::new(source).unwrap();
@@ -293,19 +1292,22 @@ impl Session {
 				&mut diagnostics
 			)
 		);
+		let diagnostics = std::ptr::NonNull::new(diagnostics as *mut _).map(|d| Blob(IUnknown(d)));
 
 		if module.is_null() {
-			let blob = Blob(IUnknown(
-				std::ptr::NonNull::new(diagnostics as *mut _).unwrap(),
-			));
-			Err(Error::Blob(blob))
+			Err(diagnostics.map(Error::Blob).unwrap_or(Error::Code(E_FAIL)))
 		} else {
 			let module = Module(IUnknown(std::ptr::NonNull::new(module as *mut _).unwrap()));
 			unsafe { (module.as_unknown().vtable().ISlangUnknown_addRef)(module.as_raw()) };
-			Ok(module)
+			Ok((module, diagnostics))
 		}
 	}
 
+	/// Loads a module serialized with [`Module::serialize`].
+	///
+	/// Serialized modules hold target-independent IR, so a module serialized in a session
+	/// for one target can be loaded into a session for any other target; target-specific
+	/// errors are reported when code is generated.
 	pub fn load_module_from_ir_blob(
 		&self,
 		module_name: &str,
@@ -327,10 +1329,9 @@ impl Session {
 		);
 
 		if module.is_null() {
-			let blob = Blob(IUnknown(
-				std::ptr::NonNull::new(diagnostics as *mut _).unwrap(),
-			));
-			Err(Error::Blob(blob))
+			Err(std::ptr::NonNull::new(diagnostics as *mut _)
+				.map(|blob| Error::Blob(Blob(IUnknown(blob))))
+				.unwrap_or(Error::Code(E_FAIL)))
 		} else {
 			let module = Module(IUnknown(std::ptr::NonNull::new(module as *mut _).unwrap()));
 			unsafe { (module.as_unknown().vtable().ISlangUnknown_addRef)(module.as_raw()) };
@@ -338,10 +1339,55 @@ impl Session {
 		}
 	}
 
+	/// Checks whether `binary_module` is still valid for the source module at `module_path`.
+	pub fn is_binary_module_up_to_date(&self, module_path: &str, binary_module: &Blob) -> bool {
+		let module_path = CString::new(module_path).unwrap();
+		vcall!(
+			self,
+			isBinaryModuleUpToDate(module_path.as_ptr(), binary_module.as_raw())
+		)
+	}
+
+	pub fn loaded_module_count(&self) -> u32 {
+		vcall!(self, getLoadedModuleCount()) as _
+	}
+
+	pub fn loaded_module_by_index(&self, index: u32) -> Option<Module> {
+		let module = vcall!(self, getLoadedModule(index as _));
+		let module = Module(IUnknown(std::ptr::NonNull::new(module as *mut _)?));
+		unsafe { (module.as_unknown().vtable().ISlangUnknown_addRef)(module.as_raw()) };
+		Some(module)
+	}
+
+	pub fn loaded_modules(&self) -> impl ExactSizeIterator<Item = Module> {
+		(0..self.loaded_module_count()).map(|i| self.loaded_module_by_index(i).unwrap())
+	}
+
+	/// Collects the entry points defined in every module loaded into this session.
+	pub fn all_entry_points(&self) -> Vec<EntryPoint> {
+		self.loaded_modules()
+			.flat_map(|module| module.entry_points().collect::<Vec<_>>())
+			.collect()
+	}
+
 	pub fn create_composite_component_type(
 		&self,
 		components: &[ComponentType],
 	) -> Result<ComponentType> {
+		// Slang doesn't diagnose this itself and fails in confusing ways later on.
+		#[cfg(debug_assertions)]
+		{
+			let session = unsafe { self.as_raw::<c_void>() };
+			if components
+				.iter()
+				.any(|c| vcall!(c, getSession()) as *mut c_void != session)
+			{
+				return Err(Error::Message(
+					"all components of a composite must be created from the same session".into(),
+				));
+			}
+		}
+
 		let mut composite_component_type = null_mut();
 		let mut diagnostics = null_mut();
 
@@ -362,6 +1408,105 @@ impl Session {
 			std::ptr::NonNull::new(composite_component_type as *mut _).unwrap(),
 		)))
 	}
+
+	/// Returns the sequential ID Slang assigns to `ty`'s conformance to `interface`, which is
+	/// the value dynamic dispatch code expects in place of the witness table.
+	pub fn type_conformance_id(
+		&self,
+		ty: &reflection::Type,
+		interface: &reflection::Type,
+	) -> Result<u32> {
+		let mut id = 0;
+		result_from_blob(
+			vcall!(
+				self,
+				getTypeConformanceWitnessSequentialID(
+					ty as *const _ as *mut _,
+					interface as *const _ as *mut _,
+					&mut id
+				)
+			),
+			null_mut(),
+		)?;
+		Ok(id)
+	}
+
+	/// Creates a component declaring that `ty` conforms to `interface`, to be linked into
+	/// programs using dynamic dispatch. `id_override` forces the conformance ID.
+	pub fn create_type_conformance_component_type(
+		&self,
+		ty: &reflection::Type,
+		interface: &reflection::Type,
+		id_override: Option<i64>,
+	) -> Result<TypeConformance> {
+		let mut conformance = null_mut();
+		let mut diagnostics = null_mut();
+
+		result_from_blob(
+			vcall!(
+				self,
+				createTypeConformanceComponentType(
+					ty as *const _ as *mut _,
+					interface as *const _ as *mut _,
+					&mut conformance,
+					id_override.unwrap_or(-1),
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(TypeConformance(IUnknown(
+			std::ptr::NonNull::new(conformance as *mut _).unwrap(),
+		)))
+	}
+
+	/// Loads `source` as a module and snapshots the layout of `type_name` for the first target,
+	/// without needing entry points or a full program.
+	pub fn reflect_type_from_source(
+		&self,
+		source: &str,
+		type_name: &str,
+	) -> Result<reflection::ReflectionSnapshot> {
+		use std::hash::{Hash, Hasher};
+
+		// Slang caches modules by name, so each distinct source needs its own.
+		let mut hasher = std::collections::hash_map::DefaultHasher::new();
+		source.hash(&mut hasher);
+		let name = format!("reflect_type_{:x}", hasher.finish());
+
+		let module =
+			self.load_module_from_source_string(&name, &format!("{}.slang", name), source)?;
+		let program = module.downcast().link()?;
+		let reflection = program.layout(0)?;
+
+		let layout = reflection
+			.find_type_by_name(type_name)
+			.and_then(|ty| reflection.type_layout(ty, LayoutRules::Default))
+			.ok_or_else(|| Error::Message(format!("type `{}` not found", type_name)))?;
+
+		Ok(reflection::ReflectionSnapshot::new(layout))
+	}
+
+	/// Composes `module` with the named entry points and links the result.
+	pub fn program(&self, module: &Module, entry_points: &[&str]) -> Result<ShaderProgram> {
+		let mut components = vec![module.downcast().clone()];
+		for name in entry_points {
+			let entry_point = module
+				.find_entry_point_by_name(name)
+				.ok_or_else(|| Error::Message(format!("entry point `{name}` not found")))?;
+			components.push(entry_point.downcast().clone());
+		}
+
+		let linked = self.create_composite_component_type(&components)?.link()?;
+		let layout = linked.layout(0)? as *const _;
+
+		Ok(ShaderProgram {
+			linked,
+			layout,
+			entry_points: entry_points.iter().map(|name| name.to_string()).collect(),
+		})
+	}
 }
 
 #[repr(transparent)]
@@ -392,6 +1537,39 @@ impl Metadata {
 		);
 		succeeded(result).then(|| used)
 	}
+
+	/// Returns the `(space, register)` pairs of `category` that are used, probing every location
+	/// up to the highest space and register `layout` assigns to a global or entry point
+	/// parameter. Slang has no API to enumerate them directly. Unbounded arrays are only probed
+	/// at their first register.
+	pub fn used_parameter_locations(
+		&self,
+		layout: &reflection::Shader,
+		category: ParameterCategory,
+	) -> Vec<(u64, u64)> {
+		let parameters = layout
+			.parameters()
+			.chain(layout.entry_points().flat_map(|e| e.parameters()))
+			.filter(|p| p.categories().any(|c| c == category));
+
+		let (mut space_count, mut register_count) = (0, 0);
+		for parameter in parameters {
+			// Slang reports unbounded sizes as `usize::MAX`.
+			let size = match parameter.type_layout().map_or(1, |t| t.size(category)) {
+				usize::MAX => 1,
+				size => size.max(1),
+			};
+			space_count = space_count.max(parameter.binding_space_with_category(category) + 1);
+			register_count = register_count.max(parameter.offset(category).saturating_add(size));
+		}
+
+		(0..space_count as u64)
+			.flat_map(|space| (0..register_count as u64).map(move |register| (space, register)))
+			.filter(|&(space, register)| {
+				self.is_parameter_location_used(category, space, register) == Some(true)
+			})
+			.collect()
+	}
 }
 
 #[repr(transparent)]
@@ -409,6 +1587,10 @@ unsafe impl Interface for ComponentType {
 }
 
 impl ComponentType {
+	pub fn specialization_param_count(&self) -> i64 {
+		vcall!(self, getSpecializationParamCount())
+	}
+
 	pub fn layout(&self, target: i64) -> Result<&reflection::Shader> {
 		let mut diagnostics = null_mut();
 		let ptr = vcall!(self, getLayout(target, &mut diagnostics));
@@ -422,6 +1604,28 @@ impl ComponentType {
 		}
 	}
 
+	pub fn specialize(&self, args: &[SpecializationArg]) -> Result<ComponentType> {
+		let mut specialized_component_type = null_mut();
+		let mut diagnostics = null_mut();
+
+		result_from_blob(
+			vcall!(
+				self,
+				specialize(
+					args.as_ptr() as _,
+					args.len() as _,
+					&mut specialized_component_type,
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(ComponentType(IUnknown(
+			std::ptr::NonNull::new(specialized_component_type as *mut _).unwrap(),
+		)))
+	}
+
 	pub fn link(&self) -> Result<ComponentType> {
 		let mut linked_component_type = null_mut();
 		let mut diagnostics = null_mut();
@@ -437,6 +1641,13 @@ impl ComponentType {
 	}
 
 	pub fn target_code(&self, target: i64) -> Result<Blob> {
+		self.target_code_with_diagnostics(target)
+			.map(|(code, _)| code)
+	}
+
+	/// Like [`ComponentType::target_code`], but also returns the diagnostics (such as
+	/// downstream compiler warnings) produced when code generation succeeded.
+	pub fn target_code_with_diagnostics(&self, target: i64) -> Result<(Blob, Option<Blob>)> {
 		let mut code = null_mut();
 		let mut diagnostics = null_mut();
 
@@ -445,12 +1656,60 @@ impl ComponentType {
 			diagnostics,
 		)?;
 
-		Ok(Blob(IUnknown(
-			std::ptr::NonNull::new(code as *mut _).unwrap(),
-		)))
+		Ok((
+			Blob(IUnknown(std::ptr::NonNull::new(code as *mut _).unwrap())),
+			std::ptr::NonNull::new(diagnostics as *mut _).map(|d| Blob(IUnknown(d))),
+		))
+	}
+
+	/// Generates the code for every target of `session`, the session this program was created
+	/// in. A linked program is reused for each target without linking again, and every target
+	/// reports its own code or error.
+	pub fn all_target_codes(&self, session: &Session) -> Vec<Result<Blob>> {
+		(0..session.target_count() as i64)
+			.map(|target| self.target_code(target))
+			.collect()
+	}
+
+	/// Lists the `OpCapability`s declared by the SPIR-V generated for `target`, e.g.
+	/// `GroupNonUniformArithmetic` for a shader using `WaveActiveSum`. Capabilities
+	/// without a known name are reported by number.
+	pub fn required_capabilities(&self, target: i64) -> Result<Vec<String>> {
+		let code = self.target_code(target)?;
+		let capabilities = spirv::capabilities(code.as_slice())
+			.ok_or_else(|| Error::Message("target code is not SPIR-V".into()))?;
+
+		Ok(capabilities
+			.into_iter()
+			.map(|c| match spirv::capability_name(c) {
+				Some(name) => name.to_string(),
+				None => c.to_string(),
+			})
+			.collect())
+	}
+
+	pub fn write_target_code_to_file(
+		&self,
+		target: i64,
+		path: impl AsRef<std::path::Path>,
+	) -> Result<()> {
+		let code = self.target_code(target)?;
+		std::fs::write(path, code.as_slice())?;
+		Ok(())
 	}
 
 	pub fn entry_point_code(&self, index: i64, target: i64) -> Result<Blob> {
+		self.entry_point_code_with_diagnostics(index, target)
+			.map(|(code, _)| code)
+	}
+
+	/// Like [`ComponentType::entry_point_code`], but also returns the diagnostics (such as
+	/// downstream compiler warnings) produced when code generation succeeded.
+	pub fn entry_point_code_with_diagnostics(
+		&self,
+		index: i64,
+		target: i64,
+	) -> Result<(Blob, Option<Blob>)> {
 		let mut code = null_mut();
 		let mut diagnostics = null_mut();
 
@@ -462,8 +1721,55 @@ impl ComponentType {
 			diagnostics,
 		)?;
 
-		Ok(Blob(IUnknown(
-			std::ptr::NonNull::new(code as *mut _).unwrap(),
+		Ok((
+			Blob(IUnknown(std::ptr::NonNull::new(code as *mut _).unwrap())),
+			std::ptr::NonNull::new(diagnostics as *mut _).map(|d| Blob(IUnknown(d))),
+		))
+	}
+
+	pub fn entry_point_host_callable(
+		&self,
+		entry_point_index: i64,
+		target_index: i64,
+	) -> Result<SharedLibrary> {
+		let mut shared_library = null_mut();
+		let mut diagnostics = null_mut();
+
+		result_from_blob(
+			vcall!(
+				self,
+				getEntryPointHostCallable(
+					entry_point_index as _,
+					target_index as _,
+					&mut shared_library,
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(SharedLibrary(IUnknown(
+			std::ptr::NonNull::new(shared_library as *mut _).unwrap(),
+		)))
+	}
+
+	pub fn result_as_file_system(
+		&self,
+		entry_point_index: i64,
+		target_index: i64,
+	) -> Result<ResultFileSystem> {
+		let mut file_system = null_mut();
+
+		result_from_blob(
+			vcall!(
+				self,
+				getResultAsFileSystem(entry_point_index, target_index, &mut file_system)
+			),
+			null_mut(),
+		)?;
+
+		Ok(ResultFileSystem(IUnknown(
+			std::ptr::NonNull::new(file_system as *mut _).unwrap(),
 		)))
 	}
 
@@ -509,6 +1815,29 @@ impl ComponentType {
 			std::ptr::NonNull::new(metadata as *mut _).unwrap(),
 		)))
 	}
+
+	/// Returns a mask with bit `1 << stage` set for each stage whose entry points use `parameter`.
+	/// Slang doesn't track this directly, so every entry point's metadata is checked.
+	pub fn parameter_stage_mask(
+		&self,
+		parameter: &reflection::VariableLayout,
+		target_index: i64,
+	) -> Result<u32> {
+		let binding = reflection::ParameterBinding::new(parameter);
+		let mut mask = 0;
+
+		for (index, entry_point) in self.layout(target_index)?.entry_points().enumerate() {
+			let metadata = self.entry_point_metadata(index as i64, target_index)?;
+			let used =
+				metadata.is_parameter_location_used(binding.category, binding.space, binding.index);
+
+			if used == Some(true) {
+				mask |= 1 << entry_point.stage() as u32;
+			}
+		}
+
+		Ok(mask)
+	}
 }
 
 #[repr(transparent)]
@@ -600,42 +1929,209 @@ impl Module {
 		)?)))
 	}
 
+	pub fn try_entry_point_by_index(&self, index: u32) -> Result<EntryPoint> {
+		let mut entry_point = null_mut();
+		result_from_blob(
+			vcall!(self, getDefinedEntryPoint(index as _, &mut entry_point)),
+			null_mut(),
+		)?;
+		Ok(EntryPoint(IUnknown(
+			std::ptr::NonNull::new(entry_point as *mut _).ok_or(Error::Code(E_FAIL))?,
+		)))
+	}
+
+	/// # Panics
+	///
+	/// Panics if any entry point cannot be retrieved; see [`Module::try_entry_points`].
 	pub fn entry_points(&self) -> impl ExactSizeIterator<Item = EntryPoint> {
 		(0..self.entry_point_count()).map(|i| self.entry_point_by_index(i).unwrap())
 	}
 
-	pub fn name(&self) -> &str {
+	pub fn try_entry_points(&self) -> impl ExactSizeIterator<Item = Result<EntryPoint>> {
+		(0..self.entry_point_count()).map(|i| self.try_entry_point_by_index(i))
+	}
+
+	/// Whether `function` is one of this module's entry points, i.e. declared with `[shader(...)]`.
+	pub fn is_entry_point(&self, function: &reflection::Function) -> bool {
+		self.defined_entry_point_index(function).is_some()
+	}
+
+	/// The stage of `function` if it is one of this module's entry points. Lays out the module
+	/// for the first target on every call, prefer [`Module::shader_entry_points`] to query all
+	/// of them at once.
+	pub fn entry_point_stage(&self, function: &reflection::Function) -> Option<Stage> {
+		let index = self.defined_entry_point_index(function)?;
+		let program = self.with_entry_points().ok()?;
+		let stage = program.layout(0).ok()?.entry_point_by_index(index)?.stage();
+		Some(stage)
+	}
+
+	/// The name and stage of every entry point declared with `[shader(...)]` in this module,
+	/// as laid out for the first target.
+	pub fn shader_entry_points(&self) -> Result<Vec<(String, Stage)>> {
+		let program = self.with_entry_points()?;
+		let entry_points = program
+			.layout(0)?
+			.entry_points()
+			.filter_map(|entry_point| Some((entry_point.name()?.to_string(), entry_point.stage())))
+			.collect();
+
+		Ok(entry_points)
+	}
+
+	// Compares function identity rather than names, so overloads of an entry point don't match.
+	fn defined_entry_point_index(&self, function: &reflection::Function) -> Option<u32> {
+		self.entry_points()
+			.position(|e| e.function_reflection() == function)
+			.map(|index| index as u32)
+	}
+
+	// The module composed with all of its entry points, whose layout lists them in the order
+	// they are defined. Laying it out doesn't require linking.
+	fn with_entry_points(&self) -> Result<ComponentType> {
+		let session = vcall!(self.downcast(), getSession());
+		// Composing doesn't read the target count, so it is left at zero.
+		let session = std::mem::ManuallyDrop::new(Session(
+			IUnknown(std::ptr::NonNull::new(session as *mut _).ok_or(Error::Code(E_FAIL))?),
+			0,
+		));
+
+		let components = std::iter::once(self.downcast().clone())
+			.chain(self.entry_points().map(|e| e.downcast().clone()))
+			.collect::<Vec<_>>();
+		session.create_composite_component_type(&components)
+	}
+
+	pub fn name(&self) -> Option<&str> {
 		let name = vcall!(self, getName());
-		unsafe { CStr::from_ptr(name).to_str().unwrap() }
+		unsafe { str_from_ptr(name) }
 	}
 
-	pub fn file_path(&self) -> &str {
+	pub fn file_path(&self) -> Option<&str> {
 		let path = vcall!(self, getFilePath());
-		unsafe { CStr::from_ptr(path).to_str().unwrap() }
+		unsafe { str_from_ptr(path) }
 	}
 
-	pub fn unique_identity(&self) -> &str {
+	pub fn unique_identity(&self) -> Option<&str> {
 		let identity = vcall!(self, getUniqueIdentity());
-		unsafe { CStr::from_ptr(identity).to_str().unwrap() }
+		unsafe { str_from_ptr(identity) }
 	}
 
 	pub fn dependency_file_count(&self) -> i32 {
 		vcall!(self, getDependencyFileCount()) as i32
 	}
 
-	pub fn dependency_file_path(&self, index: i32) -> &str {
+	pub fn dependency_file_path(&self, index: i32) -> Option<&str> {
 		let path = vcall!(self, getDependencyFilePath(index as i32));
-		unsafe { CStr::from_ptr(path).to_str().unwrap() }
+		unsafe { str_from_ptr(path) }
 	}
 
-	pub fn dependency_file_paths(&self) -> impl ExactSizeIterator<Item = &str> {
+	/// Paths of the files the module depends on, `None` for paths that aren't valid UTF-8.
+	pub fn dependency_file_paths(&self) -> impl ExactSizeIterator<Item = Option<&str>> {
 		(0..self.dependency_file_count()).map(|i| self.dependency_file_path(i))
 	}
 
+	/// Serializes the module so it can be loaded again with [`Session::load_module_from_ir_blob`].
+	pub fn serialize(&self) -> Result<Blob> {
+		let mut blob = null_mut();
+		result_from_blob(vcall!(self, serialize(&mut blob)), null_mut())?;
+
+		Ok(Blob(IUnknown(
+			std::ptr::NonNull::new(blob as *mut _).unwrap(),
+		)))
+	}
+
+	/// Writes the serialized module to `path`. Naming it `<module>.slang-module` next to the
+	/// source, or in a search path, lets sessions with
+	/// [`CompilerOptions::use_up_to_date_binary_module`] load it instead of recompiling.
+	pub fn write_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+		let path = path.as_ref();
+		let path = path.to_str().ok_or_else(|| {
+			std::io::Error::new(
+				std::io::ErrorKind::InvalidInput,
+				format!("{} is not valid UTF-8", path.display()),
+			)
+		})?;
+		let path = CString::new(path).unwrap();
+		result_from_blob(vcall!(self, writeToFile(path.as_ptr())), null_mut())
+	}
+
 	pub fn module_reflection(&self) -> &reflection::Decl {
 		let ptr = vcall!(self, getModuleReflection());
 		unsafe { &*(ptr as *const _) }
 	}
+
+	/// Every struct type declared in the module, including those nested in namespaces.
+	pub fn struct_types(&self) -> Vec<&reflection::Type> {
+		fn collect<'a>(decl: &'a reflection::Decl, out: &mut Vec<&'a reflection::Type>) {
+			for child in decl.children() {
+				match child.kind() {
+					DeclKind::Struct => out.extend(child.as_type()),
+					DeclKind::Namespace => collect(child, out),
+					_ => {}
+				}
+			}
+		}
+
+		let mut types = Vec::new();
+		collect(self.module_reflection(), &mut types);
+		types
+	}
+}
+
+/// A linked program made of a module and some of its entry points, see [`Session::program`].
+#[derive(Clone)]
+pub struct ShaderProgram {
+	linked: ComponentType,
+	// Owned by `linked`, which keeps it alive.
+	layout: *const reflection::Shader,
+	entry_points: Vec<String>,
+}
+
+impl ShaderProgram {
+	pub fn component_type(&self) -> &ComponentType {
+		&self.linked
+	}
+
+	/// Reflection for the first target of the session.
+	pub fn reflection(&self) -> &reflection::Shader {
+		unsafe { &*self.layout }
+	}
+
+	pub fn code(&self, target: i64) -> Result<Blob> {
+		self.linked.target_code(target)
+	}
+
+	pub fn entry_point_code(&self, name: &str, target: i64) -> Result<Blob> {
+		let index = self
+			.entry_points
+			.iter()
+			.position(|entry_point| entry_point == name)
+			.ok_or_else(|| Error::Message(format!("entry point `{name}` not found")))?;
+
+		self.linked.entry_point_code(index as _, target)
+	}
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct SpecializationArg<'a> {
+	inner: sys::slang_SpecializationArg,
+	_phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> SpecializationArg<'a> {
+	pub fn from_type(ty: &'a reflection::Type) -> Self {
+		Self {
+			inner: sys::slang_SpecializationArg {
+				kind: sys::slang_SpecializationArg_Kind::Type,
+				__bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 {
+					type_: ty as *const _ as *mut _,
+				},
+			},
+			_phantom: PhantomData,
+		}
+	}
 }
 
 #[repr(transparent)]
@@ -665,6 +2161,27 @@ impl Default for TargetDesc<'_> {
 }
 
 impl<'a> TargetDesc<'a> {
+	/// SPIR-V for Vulkan, using the `spirv_1_5` profile.
+	pub fn spirv_vulkan(global_session: &GlobalSession) -> Self {
+		Self::default()
+			.format(CompileTarget::Spirv)
+			.profile(global_session.find_profile("spirv_1_5"))
+	}
+
+	/// DXIL for Direct3D 12, using shader model 6.6.
+	pub fn dxil_sm66(global_session: &GlobalSession) -> Self {
+		Self::default()
+			.format(CompileTarget::Dxil)
+			.profile(global_session.find_profile("sm_6_6"))
+	}
+
+	/// GLSL source, using the `glsl_450` profile.
+	pub fn glsl_450(global_session: &GlobalSession) -> Self {
+		Self::default()
+			.format(CompileTarget::Glsl)
+			.profile(global_session.find_profile("glsl_450"))
+	}
+
 	pub fn format(mut self, format: CompileTarget) -> Self {
 		self.inner.format = format;
 		self
@@ -675,6 +2192,8 @@ impl<'a> TargetDesc<'a> {
 		self
 	}
 
+	/// Options applying to this target only, overriding the session's options, e.g. a
+	/// different [`CompilerOptions::optimization`] level per target.
 	pub fn options(mut self, options: &'a CompilerOptions) -> Self {
 		self.inner.compilerOptionEntries = options.options.as_ptr() as _;
 		self.inner.compilerOptionEntryCount = options.options.len() as _;
@@ -682,9 +2201,12 @@ impl<'a> TargetDesc<'a> {
 	}
 }
 
-#[repr(transparent)]
 pub struct SessionDesc<'a> {
 	inner: sys::slang_SessionDesc,
+	file_system: Option<Rc<dyn FileSystem>>,
+	mutable_file_system: Option<Rc<dyn MutableFileSystem>>,
+	virtual_files: Option<VirtualDirectory>,
+	target_labels: &'a [&'a str],
 	_phantom: PhantomData<&'a ()>,
 }
 
@@ -703,6 +2225,10 @@ impl Default for SessionDesc<'_> {
 				structureSize: std::mem::size_of::<sys::slang_SessionDesc>(),
 				..unsafe { std::mem::zeroed() }
 			},
+			file_system: None,
+			mutable_file_system: None,
+			virtual_files: None,
+			target_labels: &[],
 			_phantom: PhantomData,
 		}
 	}
@@ -715,6 +2241,23 @@ impl<'a> SessionDesc<'a> {
 		self
 	}
 
+	/// Names the targets, in the same order as passed to [`SessionDesc::targets`].
+	pub fn target_labels(mut self, labels: &'a [&'a str]) -> Self {
+		self.target_labels = labels;
+		self
+	}
+
+	/// Returns the index of the target labeled `label`, for use as a `target_index`.
+	pub fn target_index_of(&self, label: &str) -> Option<i64> {
+		let index = self.target_labels.iter().position(|l| *l == label)?;
+		(index < self.inner.targetCount as usize).then_some(index as i64)
+	}
+
+	pub fn flags(mut self, flags: SessionFlags) -> Self {
+		self.inner.flags = flags;
+		self
+	}
+
 	pub fn search_paths(mut self, paths: &'a [*const i8]) -> Self {
 		self.inner.searchPaths = paths.as_ptr();
 		self.inner.searchPathCount = paths.len() as _;
@@ -726,6 +2269,61 @@ impl<'a> SessionDesc<'a> {
 		self.inner.compilerOptionEntryCount = options.options.len() as _;
 		self
 	}
+
+	/// Replaces the file system Slang uses to load modules and includes.
+	pub fn file_system(mut self, file_system: impl FileSystem + 'static) -> Self {
+		self.file_system = Some(Rc::new(file_system));
+		self
+	}
+
+	/// Replaces the file system Slang uses, letting it also write outputs into `file_system`.
+	/// Takes precedence over [`SessionDesc::file_system`].
+	pub fn mutable_file_system(mut self, file_system: impl MutableFileSystem + 'static) -> Self {
+		self.mutable_file_system = Some(Rc::new(file_system));
+		self
+	}
+
+	/// Makes every file in `files` loadable at its path, on top of the session's file system.
+	/// Other paths still load from [`SessionDesc::mutable_file_system`] or
+	/// [`SessionDesc::file_system`] if set, and from disk through [`OsFileSystem`] otherwise.
+	pub fn virtual_files(mut self, files: std::collections::HashMap<String, Vec<u8>>) -> Self {
+		let directory = self
+			.virtual_files
+			.get_or_insert_with(VirtualDirectory::default);
+		for (path, content) in files {
+			directory.insert(&path, content);
+		}
+		self
+	}
+
+	/// Makes `content` loadable at `virtual_path`, on top of the session's file system, like
+	/// [`SessionDesc::virtual_files`] with a single file.
+	pub fn include_from_memory(self, virtual_path: &str, content: &[u8]) -> Self {
+		self.virtual_files(std::collections::HashMap::from([(
+			virtual_path.to_string(),
+			content.to_vec(),
+		)]))
+	}
+}
+
+/// A plain description of a session that, unlike [`SessionDesc`], owns its data and can be
+/// persisted with the `serde` feature. Create a session from it with
+/// [`GlobalSession::create_session_from_config`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionConfig {
+	pub targets: Vec<TargetConfig>,
+	pub search_paths: Vec<String>,
+	pub defines: Vec<(String, String)>,
+	pub optimization: Option<OptimizationLevel>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetConfig {
+	pub format: CompileTarget,
+	/// Profile name as accepted by [`GlobalSession::find_profile`].
+	pub profile: String,
 }
 
 macro_rules! option {
@@ -758,6 +2356,18 @@ pub struct CompilerOptions {
 }
 
 impl CompilerOptions {
+	/// Sets an integer option the typed methods don't cover yet. For boolean options, `i0` is
+	/// 0 or 1 and `i1` is unused.
+	pub fn push_int_option(self, name: CompilerOptionName, i0: i32, i1: i32) -> Self {
+		self.push_ints(name, i0, i1)
+	}
+
+	/// Sets a string option the typed methods don't cover yet. Pass `""` for `s1` if the option
+	/// only takes one string.
+	pub fn push_string_option(self, name: CompilerOptionName, s0: &str, s1: &str) -> Self {
+		self.push_str2(name, s0, s1)
+	}
+
 	fn push_ints(mut self, name: CompilerOptionName, i0: i32, i1: i32) -> Self {
 		self.options.push(sys::slang_CompilerOptionEntry {
 			name,
@@ -788,6 +2398,64 @@ impl CompilerOptions {
 		self
 	}
 
+	/// Checks for contradictory entries, such as enabling both row and column major matrix
+	/// layouts, or setting an option that only takes one value to different values.
+	pub fn validate(&self) -> Result<()> {
+		// Options for which only the last value takes effect.
+		const SINGLE_VALUED: &[CompilerOptionName] = &[
+			CompilerOptionName::Language,
+			CompilerOptionName::Profile,
+			CompilerOptionName::Stage,
+			CompilerOptionName::Target,
+			CompilerOptionName::FloatingPointMode,
+			CompilerOptionName::DebugInformation,
+			CompilerOptionName::LineDirectiveMode,
+			CompilerOptionName::Optimization,
+			CompilerOptionName::EmitSpirvDirectly,
+		];
+
+		let enabled = |name| {
+			self.options
+				.iter()
+				.any(|o| o.name == name && o.value.intValue0 != 0)
+		};
+
+		if enabled(CompilerOptionName::MatrixLayoutRow)
+			&& enabled(CompilerOptionName::MatrixLayoutColumn)
+		{
+			return Err(Error::Message(
+				"both row and column major matrix layouts are enabled".into(),
+			));
+		}
+
+		let same_value = |a: &sys::slang_CompilerOptionValue,
+		                  b: &sys::slang_CompilerOptionValue| {
+			a.kind == b.kind
+				&& a.intValue0 == b.intValue0
+				&& a.intValue1 == b.intValue1
+				&& unsafe {
+					str_from_ptr(a.stringValue0) == str_from_ptr(b.stringValue0)
+						&& str_from_ptr(a.stringValue1) == str_from_ptr(b.stringValue1)
+				}
+		};
+
+		for (i, option) in self.options.iter().enumerate() {
+			let conflicting = SINGLE_VALUED.contains(&option.name)
+				&& self.options[..i]
+					.iter()
+					.any(|o| o.name == option.name && !same_value(&o.value, &option.value));
+
+			if conflicting {
+				return Err(Error::Message(format!(
+					"{:?} is set to conflicting values",
+					option.name
+				)));
+			}
+		}
+
+		Ok(())
+	}
+
 	fn push_str1(mut self, name: CompilerOptionName, s0: &str) -> Self {
 		let s0 = CString::new(s0).unwrap();
 		let s0_ptr = s0.as_ptr();
@@ -811,6 +2479,21 @@ impl CompilerOptions {
 
 impl CompilerOptions {
 	option!(MacroDefine, macro_define(key: &str, value: &str));
+
+	/// Defines every macro in `defines`, see [`CompilerOptions::macro_define`].
+	pub fn defines(self, defines: &std::collections::HashMap<String, String>) -> Self {
+		self.defines_iter(defines)
+	}
+
+	pub fn defines_iter<K: AsRef<str>, V: AsRef<str>>(
+		self,
+		defines: impl IntoIterator<Item = (K, V)>,
+	) -> Self {
+		defines.into_iter().fold(self, |options, (key, value)| {
+			options.macro_define(key.as_ref(), value.as_ref())
+		})
+	}
+
 	option!(Include, include(path: &str));
 	option!(Language, language(language: SourceLanguage));
 	option!(MatrixLayoutColumn, matrix_layout_column(enable: bool));
@@ -830,6 +2513,7 @@ impl CompilerOptions {
 	option!(ReportDownstreamTime, report_downstream_time(enable: bool));
 	option!(ReportPerfBenchmark, report_perf_benchmark(enable: bool));
 	option!(SkipSPIRVValidation, skip_spirv_validation(enable: bool));
+	option!(DownstreamArgs, downstream_args(compiler: &str, args: &str));
 
 	// Target
 	#[inline(always)]
@@ -848,9 +2532,19 @@ impl CompilerOptions {
 	option!(VulkanUseEntryPointName, vulkan_use_entry_point_name(enable: bool));
 	option!(GLSLForceScalarLayout, glsl_force_scalar_layout(enable: bool));
 	option!(EmitSpirvDirectly, emit_spirv_directly(enable: bool));
+	option!(NoHLSLBinding, no_hlsl_binding(enable: bool));
+	option!(UseUpToDateBinaryModule, use_up_to_date_binary_module(enable: bool));
+
+	// Keeps unused parameters in the generated code, so bindings match reflection
+	// (which is computed before optimization) at every optimization level.
+	option!(PreserveParameters, preserve_parameters(enable: bool));
 
 	// Debugging
 	option!(NoCodeGen, no_code_gen(enable: bool));
+	option!(DumpIntermediates, dump_intermediates(enable: bool));
+	option!(DumpIntermediatePrefix, dump_intermediate_prefix(prefix: &str));
+	option!(DumpIr, dump_ir(enable: bool));
+	option!(DumpAst, dump_ast(enable: bool));
 
 	// Experimental
 	option!(NoMangle, no_mangle(enable: bool));
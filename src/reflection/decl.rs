@@ -6,8 +6,7 @@ pub struct Decl(sys::SlangReflectionDecl);
 
 impl Decl {
 	pub fn name(&self) -> Option<&str> {
-		let name = rcall!(spReflectionDecl_getName(self));
-		(!name.is_null()).then(|| unsafe { std::ffi::CStr::from_ptr(name).to_str().unwrap() })
+		rcall!(spReflectionDecl_getName(self) as Option<&str>)
 	}
 
 	pub fn kind(&self) -> DeclKind {
@@ -30,6 +29,16 @@ impl Decl {
 		rcall!(spReflection_getTypeFromDecl(self) as Option<&Type>)
 	}
 
+	/// The type declared by a struct. Slang reports other type declarations, such as enums and
+	/// interfaces, as unsupported for reflection.
+	pub fn as_type(&self) -> Option<&Type> {
+		if self.kind() == DeclKind::Struct {
+			self.ty()
+		} else {
+			None
+		}
+	}
+
 	pub fn as_variable(&self) -> Option<&Variable> {
 		rcall!(spReflectionDecl_castToVariable(self) as Option<&Variable>)
 	}
@@ -1,5 +1,5 @@
-use super::{Function, TypeLayout, VariableLayout, rcall};
-use crate::{Stage, sys};
+use super::{Function, ParameterBinding, TypeLayout, UserAttribute, VariableLayout, rcall};
+use crate::{GlobalSession, Stage, TypeKind, sys};
 
 #[repr(transparent)]
 pub struct EntryPoint(sys::SlangReflectionEntryPoint);
@@ -25,14 +25,66 @@ impl EntryPoint {
 		(0..self.parameter_count()).map(|i| self.parameter_by_index(i).unwrap())
 	}
 
+	/// The name, category, register and space of every entry point parameter, e.g. for
+	/// building a root signature.
+	pub fn parameter_bindings(&self) -> Vec<ParameterBinding> {
+		self.parameters().map(ParameterBinding::new).collect()
+	}
+
 	pub fn function(&self) -> Option<&Function> {
 		rcall!(spReflectionEntryPoint_getFunction(self) as Option<&Function>)
 	}
 
+	/// User-defined attributes on the entry point function.
+	///
+	/// Built-in attributes such as `[numthreads]` or `[maxvertexcount]` are not
+	/// reported here; use the dedicated accessors instead.
+	pub fn user_attributes(&self) -> impl Iterator<Item = &UserAttribute> {
+		self.function()
+			.into_iter()
+			.flat_map(|f| f.user_attributes())
+	}
+
+	pub fn find_user_attribute_by_name(
+		&self,
+		global_session: &GlobalSession,
+		name: &str,
+	) -> Option<&UserAttribute> {
+		self.function()?
+			.find_user_attribute_by_name(global_session, name)
+	}
+
 	pub fn stage(&self) -> Stage {
 		rcall!(spReflectionEntryPoint_getStage(self))
 	}
 
+	/// Whether this is a ray generation, intersection, any hit, closest hit, miss or callable shader.
+	pub fn is_ray_tracing(&self) -> bool {
+		matches!(
+			self.stage(),
+			Stage::RayGeneration
+				| Stage::Intersection
+				| Stage::AnyHit
+				| Stage::ClosestHit
+				| Stage::Miss
+				| Stage::Callable
+		)
+	}
+
+	/// For ray tracing shaders, the layout of the entry point's `uniform` parameters, which form
+	/// the local root arguments (D3D12) or shader record (Vulkan) of its shader table entry.
+	pub fn local_root_layout(&self) -> Option<&TypeLayout> {
+		if !self.is_ray_tracing() {
+			return None;
+		}
+
+		let layout = self.type_layout()?;
+		match layout.kind() {
+			TypeKind::ConstantBuffer | TypeKind::ParameterBlock => layout.element_type_layout(),
+			_ => Some(layout),
+		}
+	}
+
 	pub fn compute_thread_group_size(&self) -> [u64; 3] {
 		let mut out_size = [0; 3];
 		rcall!(spReflectionEntryPoint_getComputeThreadGroupSize(
@@ -68,6 +120,26 @@ impl EntryPoint {
 		rcall!(spReflectionEntryPoint_getResultVarLayout(self) as Option<&VariableLayout>)
 	}
 
+	/// Returns the `(semantic_name, semantic_index)` of every output, flattening
+	/// struct return types, e.g. `("SV_TARGET", 1)` for `SV_Target1`.
+	pub fn output_semantics(&self) -> Vec<(&str, usize)> {
+		fn collect<'a>(layout: &'a VariableLayout, out: &mut Vec<(&'a str, usize)>) {
+			if let Some(name) = layout.semantic_name() {
+				out.push((name, layout.semantic_index()));
+			} else if let Some(type_layout) = layout.type_layout() {
+				for field in type_layout.fields() {
+					collect(field, out);
+				}
+			}
+		}
+
+		let mut out = Vec::new();
+		if let Some(result) = self.result_var_layout() {
+			collect(result, &mut out);
+		}
+		out
+	}
+
 	pub fn has_default_constant_buffer(&self) -> bool {
 		rcall!(spReflectionEntryPoint_hasDefaultConstantBuffer(self)) != 0
 	}
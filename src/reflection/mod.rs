@@ -2,7 +2,9 @@ mod decl;
 mod entry_point;
 mod function;
 mod generic;
+mod parameter_binding;
 mod shader;
+mod snapshot;
 mod ty;
 mod type_layout;
 mod type_parameter;
@@ -14,12 +16,14 @@ pub use decl::Decl;
 pub use entry_point::EntryPoint;
 pub use function::Function;
 pub use generic::Generic;
+pub use parameter_binding::ParameterBinding;
 pub use shader::Shader;
+pub use snapshot::{FieldSnapshot, ReflectionSnapshot};
 pub use ty::Type;
 pub use type_layout::TypeLayout;
 pub use type_parameter::TypeParameter;
 pub use user_attribute::UserAttribute;
-pub use variable::Variable;
+pub use variable::{ParameterDirection, Variable};
 pub use variable_layout::VariableLayout;
 
 use super::sys;
@@ -49,3 +53,27 @@ macro_rules! rcall {
 }
 
 pub(super) use rcall;
+
+// Reflection objects are only handed out by reference to Slang-owned data,
+// so two references denote the same object exactly when their addresses match.
+macro_rules! impl_identity_eq {
+	($($t:ty),*) => {
+		$(
+			impl PartialEq for $t {
+				fn eq(&self, other: &Self) -> bool {
+					std::ptr::eq(self, other)
+				}
+			}
+
+			impl Eq for $t {}
+
+			impl std::hash::Hash for $t {
+				fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+					std::ptr::hash(self, state)
+				}
+			}
+		)*
+	};
+}
+
+impl_identity_eq!(Function, Type, TypeLayout, Variable, VariableLayout);
@@ -0,0 +1,23 @@
+use super::VariableLayout;
+use crate::ParameterCategory;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterBinding {
+	pub name: String,
+	pub category: ParameterCategory,
+	pub space: u64,
+	pub index: u64,
+}
+
+impl ParameterBinding {
+	pub(crate) fn new(parameter: &VariableLayout) -> Self {
+		let category = parameter.category().unwrap_or(ParameterCategory::None);
+
+		Self {
+			name: parameter.name().unwrap_or_default().to_string(),
+			category,
+			space: parameter.binding_space_with_category(category) as u64,
+			index: parameter.offset(category) as u64,
+		}
+	}
+}
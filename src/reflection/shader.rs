@@ -1,7 +1,11 @@
 use super::{
-	EntryPoint, Function, Generic, Type, TypeLayout, TypeParameter, Variable, VariableLayout, rcall,
+	EntryPoint, Function, Generic, ParameterBinding, Type, TypeLayout, TypeParameter, Variable,
+	VariableLayout, rcall,
+};
+use crate::{
+	BindingType, GenericArg, GenericArgType, LayoutRules, ParameterCategory, Result, Stage,
+	TypeKind, sys,
 };
-use crate::{GenericArg, GenericArgType, LayoutRules, sys};
 
 #[repr(transparent)]
 pub struct Shader(sys::SlangReflection);
@@ -19,6 +23,50 @@ impl Shader {
 		(0..self.parameter_count()).map(|i| self.parameter_by_index(i).unwrap())
 	}
 
+	pub fn find_parameter_by_name(&self, name: &str) -> Option<&VariableLayout> {
+		self.parameters()
+			.find(|p| p.variable().and_then(|v| v.name()) == Some(name))
+	}
+
+	pub fn parameter_bindings(&self) -> Vec<ParameterBinding> {
+		self.parameters().map(ParameterBinding::new).collect()
+	}
+
+	/// Total number of bindings of each type across all global and entry point parameters, e.g.
+	/// for sizing descriptor pools. Unbounded arrays such as `Texture2D textures[]` have no fixed
+	/// count, so their binding type saturates at `u32::MAX`.
+	pub fn descriptor_counts(&self) -> std::collections::HashMap<BindingType, u32> {
+		let mut counts = std::collections::HashMap::new();
+		let parameters = self
+			.parameters()
+			.chain(self.entry_points().flat_map(|e| e.parameters()));
+
+		for layout in parameters.filter_map(|p| p.type_layout()) {
+			for range in 0..layout.binding_range_count() {
+				// Slang reports unbounded ranges with a negative sentinel.
+				let count =
+					u32::try_from(layout.binding_range_binding_count(range)).unwrap_or(u32::MAX);
+				let total = counts
+					.entry(layout.binding_range_type(range))
+					.or_insert(0u32);
+				*total = total.saturating_add(count);
+			}
+		}
+
+		counts
+	}
+
+	/// Parameters declared as specialization constants, e.g. with `[SpecializationConstant]`
+	/// or `[vk::constant_id(n)]`.
+	pub fn specialization_constants(&self) -> impl Iterator<Item = &VariableLayout> {
+		self.parameters()
+			.filter(|p| p.specialization_constant_id().is_some())
+	}
+
+	pub fn specialization_constant_count(&self) -> u32 {
+		self.specialization_constants().count() as _
+	}
+
 	pub fn type_parameter_count(&self) -> u32 {
 		rcall!(spReflection_GetTypeParameterCount(self))
 	}
@@ -48,6 +96,10 @@ impl Shader {
 		(0..self.entry_point_count()).map(|i| self.entry_point_by_index(i).unwrap())
 	}
 
+	pub fn entry_points_for_stage(&self, stage: Stage) -> impl Iterator<Item = &EntryPoint> {
+		self.entry_points().filter(move |e| e.stage() == stage)
+	}
+
 	pub fn find_entry_point_by_name(&self, name: &str) -> Option<&EntryPoint> {
 		let name = std::ffi::CString::new(name).unwrap();
 		rcall!(spReflection_findEntryPointByName(self, name.as_ptr()) as Option<&EntryPoint>)
@@ -136,10 +188,13 @@ impl Shader {
 		let mut len = 0;
 		let result = rcall!(spReflection_getHashedString(self, index, &mut len));
 
-		(!result.is_null()).then(|| {
-			let slice = unsafe { std::slice::from_raw_parts(result as *const u8, len as usize) };
-			std::str::from_utf8(slice).unwrap()
-		})
+		(!result.is_null())
+			.then(|| {
+				let slice =
+					unsafe { std::slice::from_raw_parts(result as *const u8, len as usize) };
+				std::str::from_utf8(slice).ok()
+			})
+			.flatten()
 	}
 
 	pub fn hashed_strings(&self) -> impl ExactSizeIterator<Item = &str> {
@@ -153,4 +208,39 @@ impl Shader {
 	pub fn global_params_var_layout(&self) -> Option<&VariableLayout> {
 		rcall!(spReflection_getGlobalParamsVarLayout(self) as Option<&VariableLayout>)
 	}
+
+	/// Initial contents for the default constant buffer holding global uniforms, with declared
+	/// default values filled in; see [`TypeLayout::default_data`].
+	pub fn default_constant_buffer_data(&self) -> Result<Vec<u8>> {
+		let Some(mut layout) = self.global_params_type_layout() else {
+			return Ok(Vec::new());
+		};
+
+		if layout.kind() == TypeKind::ConstantBuffer {
+			layout = layout.element_type_layout().unwrap_or(layout);
+		}
+
+		layout.default_data()
+	}
+
+	/// Returns the name, category and offset of every global parameter.
+	pub fn global_params(&self) -> Vec<(String, ParameterCategory, usize)> {
+		let Some(mut layout) = self.global_params_type_layout() else {
+			return Vec::new();
+		};
+
+		// Global uniforms are wrapped in an implicit constant buffer.
+		if layout.kind() == TypeKind::ConstantBuffer {
+			layout = layout.element_type_layout().unwrap_or(layout);
+		}
+
+		layout
+			.fields()
+			.map(|field| {
+				let category = field.category().unwrap_or(ParameterCategory::None);
+				let name = field.name().unwrap_or_default().to_string();
+				(name, category, field.offset(category))
+			})
+			.collect()
+	}
 }
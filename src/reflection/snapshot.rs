@@ -0,0 +1,42 @@
+use super::TypeLayout;
+use crate::{ParameterCategory, TypeKind};
+
+/// An owned copy of a type's uniform layout that outlives the session it was reflected from.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReflectionSnapshot {
+	pub name: Option<String>,
+	pub kind: TypeKind,
+	pub size: usize,
+	pub alignment: i32,
+	pub fields: Vec<FieldSnapshot>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldSnapshot {
+	pub name: String,
+	pub offset: usize,
+	pub ty: ReflectionSnapshot,
+}
+
+impl ReflectionSnapshot {
+	pub fn new(layout: &TypeLayout) -> Self {
+		Self {
+			name: layout.name().map(str::to_string),
+			kind: layout.kind(),
+			size: layout.size(ParameterCategory::Uniform),
+			alignment: layout.alignment(ParameterCategory::Uniform),
+			fields: layout
+				.fields()
+				.filter_map(|field| {
+					Some(FieldSnapshot {
+						name: field.name().unwrap_or_default().to_string(),
+						offset: field.offset(ParameterCategory::Uniform),
+						ty: ReflectionSnapshot::new(field.type_layout()?),
+					})
+				})
+				.collect(),
+		}
+	}
+}
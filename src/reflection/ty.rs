@@ -63,6 +63,15 @@ impl Type {
 		rcall!(spReflectionType_GetElementType(self) as Option<&Type>)
 	}
 
+	/// The type a `Ptr<T>` (buffer device address) points to.
+	pub fn pointee_type(&self) -> Option<&Type> {
+		if self.kind() == TypeKind::Pointer {
+			self.element_type()
+		} else {
+			None
+		}
+	}
+
 	pub fn row_count(&self) -> u32 {
 		rcall!(spReflectionType_GetRowCount(self))
 	}
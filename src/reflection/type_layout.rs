@@ -1,7 +1,7 @@
 use super::{Type, Variable, VariableLayout, rcall};
 use crate::{
-	BindingType, ImageFormat, MatrixLayoutMode, ParameterCategory, ResourceAccess, ResourceShape,
-	ScalarType, TypeKind, sys,
+	BindingType, Error, ImageFormat, MatrixLayoutMode, ParameterCategory, ResourceAccess,
+	ResourceShape, Result, ScalarType, TypeKind, sys,
 };
 
 #[repr(transparent)]
@@ -157,6 +157,88 @@ impl TypeLayout {
 		)
 	}
 
+	/// Size including any pending data of specialized existential fields,
+	/// which is what a buffer holding this layout must be able to fit.
+	pub fn total_size(&self, category: ParameterCategory) -> usize {
+		let pending_size = self
+			.pending_data_type_layout()
+			.map_or(0, |pending| pending.size(category));
+		self.size(category) + pending_size
+	}
+
+	/// A zeroed buffer of this layout's uniform size with every scalar field that declares a
+	/// default value (e.g. `int count = 4;`) filled in, recursing into nested structs.
+	///
+	/// Slang reflects defaults as 64-bit integers or 32-bit floats, so `double` defaults are
+	/// widened from single precision. Fails if a field declares a default that can't be written,
+	/// such as a vector or `half` default.
+	pub fn default_data(&self) -> Result<Vec<u8>> {
+		let mut data = vec![0; self.size(ParameterCategory::Uniform)];
+		self.write_defaults(&mut data, 0)?;
+		Ok(data)
+	}
+
+	fn write_defaults(&self, data: &mut [u8], base: usize) -> Result<()> {
+		for field in self.fields() {
+			let offset = base + field.offset(ParameterCategory::Uniform);
+			let Some(type_layout) = field.type_layout() else {
+				continue;
+			};
+
+			if type_layout.kind() == TypeKind::Struct {
+				type_layout.write_defaults(data, offset)?;
+				continue;
+			}
+
+			let Some(variable) = field.variable().filter(|v| v.has_default_value()) else {
+				continue;
+			};
+
+			let scalar_type = match type_layout.kind() {
+				TypeKind::Scalar => type_layout.scalar_type(),
+				_ => None,
+			};
+			let bytes = match scalar_type {
+				Some(ScalarType::Bool | ScalarType::Int32 | ScalarType::Uint32) => variable
+					.default_value_int()
+					.map(|value| (value as i32).to_le_bytes().to_vec()),
+				Some(ScalarType::Int64 | ScalarType::Uint64) => variable
+					.default_value_int()
+					.map(|value| value.to_le_bytes().to_vec()),
+				Some(ScalarType::Float32) => variable
+					.default_value_float()
+					.map(|value| value.to_le_bytes().to_vec()),
+				Some(ScalarType::Float64) => variable
+					.default_value_float()
+					.map(|value| f64::from(value).to_le_bytes().to_vec()),
+				_ => None,
+			};
+
+			let Some(bytes) = bytes else {
+				return Err(Error::Message(format!(
+					"can't write the default value of `{}`",
+					variable.name().unwrap_or_default()
+				)));
+			};
+
+			if let Some(dst) = data.get_mut(offset..offset + bytes.len()) {
+				dst.copy_from_slice(&bytes);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Number of existential type slots, i.e. the specialization arguments this layout needs.
+	pub fn specialization_arg_count(&self) -> usize {
+		self.size(ParameterCategory::ExistentialTypeParam)
+	}
+
+	/// Number of existential value slots, one per interface-typed value in this layout.
+	pub fn existential_value_count(&self) -> usize {
+		self.size(ParameterCategory::ExistentialObjectParam)
+	}
+
 	pub fn binding_range_count(&self) -> i64 {
 		rcall!(spReflectionTypeLayout_getBindingRangeCount(self))
 	}
@@ -304,6 +386,12 @@ impl TypeLayout {
 		))
 	}
 
+	pub fn sub_object_range_type_layout(&self, sub_object_range_index: i64) -> Option<&TypeLayout> {
+		self.binding_range_leaf_type_layout(
+			self.sub_object_range_binding_range_index(sub_object_range_index),
+		)
+	}
+
 	pub fn sub_object_range_space_offset(&self, sub_object_range_index: i64) -> i64 {
 		rcall!(spReflectionTypeLayout_getSubObjectRangeSpaceOffset(
 			self,
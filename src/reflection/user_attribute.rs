@@ -41,9 +41,12 @@ impl UserAttribute {
 			self, index, &mut len
 		));
 
-		(!result.is_null()).then(|| {
-			let slice = unsafe { std::slice::from_raw_parts(result as *const u8, len as usize) };
-			std::str::from_utf8(slice).unwrap()
-		})
+		(!result.is_null())
+			.then(|| {
+				let slice =
+					unsafe { std::slice::from_raw_parts(result as *const u8, len as usize) };
+				std::str::from_utf8(slice).ok()
+			})
+			.flatten()
 	}
 }
@@ -1,6 +1,13 @@
 use super::{Generic, Type, UserAttribute, rcall};
 use crate::{GlobalSession, Modifier, ModifierID, succeeded, sys};
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParameterDirection {
+	In,
+	Out,
+	InOut,
+}
+
 #[repr(transparent)]
 pub struct Variable(sys::SlangReflectionVariable);
 
@@ -17,6 +24,20 @@ impl Variable {
 		rcall!(spReflectionVariable_FindModifier(self, id) as Option<&Modifier>)
 	}
 
+	/// The direction of a function parameter, `In` when no direction modifier is present.
+	pub fn parameter_direction(&self) -> ParameterDirection {
+		let is_in = self.find_modifier(ModifierID::In).is_some();
+		let is_out = self.find_modifier(ModifierID::Out).is_some();
+
+		if self.find_modifier(ModifierID::Inout).is_some() || (is_in && is_out) {
+			ParameterDirection::InOut
+		} else if is_out {
+			ParameterDirection::Out
+		} else {
+			ParameterDirection::In
+		}
+	}
+
 	pub fn user_attribute_count(&self) -> u32 {
 		rcall!(spReflectionVariable_GetUserAttributeCount(self))
 	}
@@ -52,6 +73,12 @@ impl Variable {
 		if succeeded(result) { Some(value) } else { None }
 	}
 
+	pub fn default_value_float(&self) -> Option<f32> {
+		let mut value = 0.0;
+		let result = rcall!(spReflectionVariable_GetDefaultValueFloat(self, &mut value));
+		if succeeded(result) { Some(value) } else { None }
+	}
+
 	pub fn generic_container(&self) -> Option<&Generic> {
 		rcall!(spReflectionVariable_GetGenericContainer(self) as Option<&Generic>)
 	}
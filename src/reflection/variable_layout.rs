@@ -37,6 +37,18 @@ impl VariableLayout {
 		(0..self.category_count()).map(|i| self.category_by_index(i).unwrap())
 	}
 
+	pub fn is_push_constant(&self) -> bool {
+		self.categories()
+			.any(|c| c == ParameterCategory::PushConstantBuffer)
+	}
+
+	/// The constant id to use with `VkSpecializationMapEntry`, if this is a specialization constant.
+	pub fn specialization_constant_id(&self) -> Option<u32> {
+		self.categories()
+			.any(|c| c == ParameterCategory::SpecializationConstant)
+			.then(|| self.offset(ParameterCategory::SpecializationConstant) as u32)
+	}
+
 	pub fn offset(&self, category: ParameterCategory) -> usize {
 		rcall!(spReflectionVariableLayout_GetOffset(self, category))
 	}
@@ -62,8 +74,7 @@ impl VariableLayout {
 	}
 
 	pub fn semantic_name(&self) -> Option<&str> {
-		let name = rcall!(spReflectionVariableLayout_GetSemanticName(self));
-		(!name.is_null()).then(|| unsafe { std::ffi::CStr::from_ptr(name).to_str().unwrap() })
+		rcall!(spReflectionVariableLayout_GetSemanticName(self) as Option<&str>)
 	}
 
 	pub fn semantic_index(&self) -> usize {
@@ -0,0 +1,132 @@
+// Minimal SPIR-V parsing, just enough to inspect compiled modules.
+
+const MAGIC: u32 = 0x07230203;
+const HEADER_WORDS: usize = 5;
+const OP_CAPABILITY: u32 = 17;
+
+/// Returns the operand of every `OpCapability` instruction, or `None` if `code` is not SPIR-V.
+pub(crate) fn capabilities(code: &[u8]) -> Option<Vec<u32>> {
+	let chunks = code.chunks_exact(4);
+	if !chunks.remainder().is_empty() {
+		return None;
+	}
+
+	let words = chunks
+		.map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]]))
+		.collect::<Vec<_>>();
+
+	if words.len() < HEADER_WORDS || words[0] != MAGIC {
+		return None;
+	}
+
+	let mut capabilities = Vec::new();
+	let mut i = HEADER_WORDS;
+
+	while i < words.len() {
+		let word_count = (words[i] >> 16) as usize;
+		let opcode = words[i] & 0xffff;
+
+		if word_count == 0 {
+			return None;
+		}
+
+		if opcode == OP_CAPABILITY && word_count >= 2 {
+			capabilities.push(*words.get(i + 1)?);
+		}
+
+		i += word_count;
+	}
+
+	Some(capabilities)
+}
+
+pub(crate) fn capability_name(capability: u32) -> Option<&'static str> {
+	Some(match capability {
+		0 => "Matrix",
+		1 => "Shader",
+		2 => "Geometry",
+		3 => "Tessellation",
+		4 => "Addresses",
+		5 => "Linkage",
+		6 => "Kernel",
+		7 => "Vector16",
+		8 => "Float16Buffer",
+		9 => "Float16",
+		10 => "Float64",
+		11 => "Int64",
+		12 => "Int64Atomics",
+		13 => "ImageBasic",
+		14 => "ImageReadWrite",
+		15 => "ImageMipmap",
+		17 => "Pipes",
+		18 => "Groups",
+		19 => "DeviceEnqueue",
+		20 => "LiteralSampler",
+		21 => "AtomicStorage",
+		22 => "Int16",
+		23 => "TessellationPointSize",
+		24 => "GeometryPointSize",
+		25 => "ImageGatherExtended",
+		27 => "StorageImageMultisample",
+		28 => "UniformBufferArrayDynamicIndexing",
+		29 => "SampledImageArrayDynamicIndexing",
+		30 => "StorageBufferArrayDynamicIndexing",
+		31 => "StorageImageArrayDynamicIndexing",
+		32 => "ClipDistance",
+		33 => "CullDistance",
+		34 => "ImageCubeArray",
+		35 => "SampleRateShading",
+		36 => "ImageRect",
+		37 => "SampledRect",
+		38 => "GenericPointer",
+		39 => "Int8",
+		40 => "InputAttachment",
+		41 => "SparseResidency",
+		42 => "MinLod",
+		43 => "Sampled1D",
+		44 => "Image1D",
+		45 => "SampledCubeArray",
+		46 => "SampledBuffer",
+		47 => "ImageBuffer",
+		48 => "ImageMSArray",
+		49 => "StorageImageExtendedFormats",
+		50 => "ImageQuery",
+		51 => "DerivativeControl",
+		52 => "InterpolationFunction",
+		53 => "TransformFeedback",
+		54 => "GeometryStreams",
+		55 => "StorageImageReadWithoutFormat",
+		56 => "StorageImageWriteWithoutFormat",
+		57 => "MultiViewport",
+		58 => "SubgroupDispatch",
+		59 => "NamedBarrier",
+		60 => "PipeStorage",
+		61 => "GroupNonUniform",
+		62 => "GroupNonUniformVote",
+		63 => "GroupNonUniformArithmetic",
+		64 => "GroupNonUniformBallot",
+		65 => "GroupNonUniformShuffle",
+		66 => "GroupNonUniformShuffleRelative",
+		67 => "GroupNonUniformClustered",
+		68 => "GroupNonUniformQuad",
+		69 => "ShaderLayer",
+		70 => "ShaderViewportIndex",
+		4427 => "DrawParameters",
+		4433 => "StorageBuffer16BitAccess",
+		4434 => "UniformAndStorageBuffer16BitAccess",
+		4435 => "StoragePushConstant16",
+		4436 => "StorageInputOutput16",
+		4437 => "DeviceGroup",
+		4439 => "MultiView",
+		4448 => "StorageBuffer8BitAccess",
+		4449 => "UniformAndStorageBuffer8BitAccess",
+		4450 => "StoragePushConstant8",
+		4472 => "RayQueryKHR",
+		4479 => "RayTracingKHR",
+		5301 => "ShaderNonUniform",
+		5302 => "RuntimeDescriptorArray",
+		5345 => "VulkanMemoryModel",
+		5347 => "PhysicalStorageBufferAddresses",
+		_ => return None,
+	})
+}
@@ -1,5 +1,5 @@
 use crate as slang;
-use slang::Downcast;
+use slang::{CompileTargetExt, Downcast, FileSystem, ResultExt};
 
 #[test]
 fn compile() {
@@ -42,6 +42,3179 @@ fn compile() {
 	assert_eq!(reflection.entry_point_count(), 1);
 	assert_eq!(reflection.parameter_count(), 3);
 
+	let bindings = reflection.parameter_bindings();
+	let names = bindings.iter().map(|b| b.name.as_str()).collect::<Vec<_>>();
+	assert_eq!(names, ["input_0", "input_1", "output"]);
+	for (index, binding) in bindings.iter().enumerate() {
+		assert_eq!(
+			binding.category,
+			slang::ParameterCategory::DescriptorTableSlot
+		);
+		assert_eq!(binding.space, 0);
+		assert_eq!(binding.index, index as u64);
+	}
+
 	let shader_bytecode = linked_program.entry_point_code(0, 0).unwrap();
 	assert_ne!(shader_bytecode.as_slice().len(), 0);
 }
+
+// A minimal compute shader, for tests that don't depend on what the shader does.
+const COMPUTE_SHADER: &str = r#"
+	RWStructuredBuffer<float> output;
+
+	[shader("compute")]
+	[numthreads(1, 1, 1)]
+	void main(uint3 thread_id: SV_DispatchThreadID) {
+		output[thread_id.x] = 1.0;
+	}
+	"#;
+
+fn spirv_targets(global_session: &slang::GlobalSession) -> [slang::TargetDesc<'static>; 1] {
+	[slang::TargetDesc::default()
+		.format(slang::CompileTarget::Spirv)
+		.profile(global_session.find_profile("glsl_450"))]
+}
+
+fn create_session(global_session: &slang::GlobalSession) -> slang::Session {
+	let targets = spirv_targets(global_session);
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+
+	global_session.create_session(&session_desc).unwrap()
+}
+
+fn link_source(session: &slang::Session, source: &str) -> slang::ComponentType {
+	let module = session
+		.load_module_from_source_string("test", "test.slang", source)
+		.unwrap();
+
+	let mut components = vec![module.downcast().clone()];
+	components.extend(module.entry_points().map(|e| e.downcast().clone()));
+
+	let program = session
+		.create_composite_component_type(&components)
+		.unwrap();
+	program.link().unwrap()
+}
+
+#[test]
+fn texture_categories() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		Texture2D<float4> albedo;
+		SamplerState linear_sampler;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = albedo.SampleLevel(linear_sampler, float2(0.0), 0.0);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let albedo = reflection.parameter_by_index(0).unwrap();
+	assert_eq!(albedo.name(), Some("albedo"));
+
+	let type_layout = albedo.type_layout().unwrap();
+	assert_eq!(type_layout.kind(), slang::TypeKind::Resource);
+	assert_eq!(
+		type_layout.parameter_category(),
+		slang::ParameterCategory::DescriptorTableSlot
+	);
+	assert_eq!(type_layout.category_count(), 1);
+	assert_eq!(
+		type_layout.categories().collect::<Vec<_>>(),
+		[slang::ParameterCategory::DescriptorTableSlot]
+	);
+}
+
+#[test]
+fn image_format() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		[format("rgba8")]
+		RWTexture2D<float4> image;
+
+		[shader("compute")]
+		[numthreads(8, 8, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			image[thread_id.xy] = float4(1.0);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let image = reflection.parameter_by_index(0).unwrap();
+	let format = image.image_format();
+	assert_ne!(format as u32, 0, "expected a known image format");
+
+	// The binding range of the same parameter reports the format independently.
+	let globals = reflection.global_params_type_layout().unwrap();
+	let range = globals.field_binding_range_offset(0);
+	assert_eq!(globals.binding_range_image_format(range), format);
+}
+
+#[test]
+fn pending_data_layout() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			interface IShape {
+				float area();
+			}
+
+			struct Circle : IShape {
+				float radius;
+				float area() { return radius * radius * 3.14159; }
+			}
+
+			RWStructuredBuffer<float> output;
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void main(uniform IShape shape) {
+				output[0] = shape.area();
+			}
+			"#,
+		)
+		.unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+
+	let circle = program
+		.layout(0)
+		.unwrap()
+		.find_type_by_name("Circle")
+		.unwrap();
+	let specialized = program
+		.specialize(&[slang::SpecializationArg::from_type(circle)])
+		.unwrap()
+		.link()
+		.unwrap();
+
+	let reflection = specialized.layout(0).unwrap();
+	let shape = reflection
+		.entry_point_by_index(0)
+		.unwrap()
+		.parameter_by_index(0)
+		.unwrap();
+
+	let pending = shape.pending_data_layout().unwrap();
+	assert!(pending.type_layout().is_some());
+	assert!(
+		shape
+			.type_layout()
+			.unwrap()
+			.pending_data_type_layout()
+			.is_some()
+	);
+}
+
+#[test]
+fn generic_function() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		T add<T : IArithmetic>(T a, T b) {
+			return a + b;
+		}
+
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = add<float>(1.0, 2.0);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let add = reflection.find_function_by_name("add").unwrap();
+	let generic = add.generic_container().unwrap();
+	assert_eq!(generic.inner_kind(), slang::DeclKind::Func);
+	assert_eq!(generic.type_parameter_count(), 1);
+	assert_eq!(generic.value_parameter_count(), 0);
+
+	let type_parameter = generic.type_parameter_by_index(0).unwrap();
+	assert_eq!(type_parameter.name(), Some("T"));
+	assert_eq!(generic.type_parameter_constraint_count(type_parameter), 1);
+
+	let constraint = generic
+		.type_parameter_constraint_by_index(type_parameter, 0)
+		.unwrap();
+	assert_eq!(constraint.name(), Some("IArithmetic"));
+}
+
+#[test]
+fn module_decls() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			struct Light {
+				float3 direction;
+				float intensity;
+			}
+
+			float attenuate(Light light, float distance) {
+				return light.intensity / (distance * distance);
+			}
+
+			float3 shade(Light light, float3 normal) {
+				return max(dot(normal, -light.direction), 0.0) * light.intensity;
+			}
+			"#,
+		)
+		.unwrap();
+
+	let decls = module.module_reflection().children().collect::<Vec<_>>();
+	let functions = decls
+		.iter()
+		.filter_map(|decl| decl.as_function())
+		.filter_map(|function| function.name())
+		.collect::<Vec<_>>();
+	assert_eq!(functions, ["attenuate", "shade"]);
+
+	let light = decls.iter().find_map(|decl| decl.as_type()).unwrap();
+	assert_eq!(light.name(), Some("Light"));
+	assert_eq!(light.field_count(), 2);
+	assert!(decls.iter().all(|decl| decl.as_variable().is_none()));
+}
+
+#[test]
+fn function_overloads() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		float foo(float value) { return value * 2.0; }
+		int foo(int value) { return value + 1; }
+
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = foo(1.0) + float(foo(1));
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let foo = reflection.find_function_by_name("foo").unwrap();
+	assert!(foo.is_overloaded());
+	assert_eq!(foo.overload_count(), 2);
+	assert!(
+		foo.overloads()
+			.all(|overload| overload.name() == Some("foo"))
+	);
+
+	let int = reflection.find_type_by_name("int").unwrap();
+	let specialized = foo.specialize_with_arg_types(&[int]).unwrap();
+	let parameter = specialized.parameter_by_index(0).unwrap();
+	assert_eq!(
+		parameter.ty().unwrap().scalar_type(),
+		slang::ScalarType::Int32
+	);
+	assert_eq!(
+		specialized.return_type().unwrap().scalar_type(),
+		slang::ScalarType::Int32
+	);
+}
+
+#[test]
+fn host_callable() {
+	for format in [
+		slang::CompileTarget::HostCppSource,
+		slang::CompileTarget::ShaderSharedLibrary,
+		slang::CompileTarget::HostHostCallable,
+	] {
+		assert_eq!(slang::TargetDesc::default().format(format).format, format);
+	}
+
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = [slang::TargetDesc::default().format(slang::CompileTarget::HostHostCallable)];
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		export __extern_cpp int add(int a, int b) {
+			return a + b;
+		}
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {}
+		"#,
+	);
+
+	let library = program.entry_point_host_callable(0, 0).unwrap();
+	let add = library.find_symbol_address_by_name("add").unwrap();
+	let add: extern "C" fn(i32, i32) -> i32 = unsafe { std::mem::transmute(add) };
+	assert_eq!(add(2, 3), 5);
+}
+
+#[test]
+fn shared_library_symbols() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = [slang::TargetDesc::default().format(slang::CompileTarget::HostHostCallable)];
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<int> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = 1;
+		}
+		"#,
+	);
+
+	let library = program.entry_point_host_callable(0, 0).unwrap();
+	let main = library.find_symbol_address_by_name("main").unwrap();
+	assert!(!main.is_null());
+	assert!(
+		library
+			.find_symbol_address_by_name("missing_symbol")
+			.is_none()
+	);
+}
+
+#[test]
+fn optimization_levels() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let code_size = |level: slang::OptimizationLevel| {
+		let options = slang::CompilerOptions::default().optimization(level);
+		let targets = [slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450"))];
+		let session_desc = slang::SessionDesc::default()
+			.targets(&targets)
+			.options(&options);
+		let session = global_session.create_session(&session_desc).unwrap();
+
+		let program = link_source(
+			&session,
+			r#"
+			RWStructuredBuffer<float> output;
+
+			float square(float value) {
+				float result = value * value;
+				float unused = result * 3.0 + 1.0;
+				return result;
+			}
+
+			[shader("compute")]
+			[numthreads(64, 1, 1)]
+			void main(uint3 thread_id: SV_DispatchThreadID) {
+				float value = float(thread_id.x);
+				for (int i = 0; i < 4; i++)
+					value = square(value) * 0.5;
+				output[thread_id.x] = value;
+			}
+			"#,
+		);
+
+		program.entry_point_code(0, 0).unwrap().as_slice().len()
+	};
+
+	let none = code_size(slang::OptimizationLevel::None);
+	let default = code_size(slang::OptimizationLevel::Default);
+	let high = code_size(slang::OptimizationLevel::High);
+	let maximal = code_size(slang::OptimizationLevel::Maximal);
+
+	assert!(default <= none, "default ({default}) > none ({none})");
+	assert!(high <= none, "high ({high}) > none ({none})");
+	assert!(maximal <= none, "maximal ({maximal}) > none ({none})");
+}
+
+#[test]
+fn module_strings() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"in_memory",
+			"in_memory.slang",
+			"float identity(float x) { return x; }",
+		)
+		.unwrap();
+
+	assert_eq!(module.name(), Some("in_memory"));
+	assert_eq!(module.file_path(), Some("in_memory.slang"));
+	assert!(
+		module
+			.unique_identity()
+			.unwrap()
+			.ends_with("in_memory.slang")
+	);
+
+	let paths = module.dependency_file_paths();
+	assert_eq!(paths.len(), module.dependency_file_count() as usize);
+	assert!(paths.collect::<Vec<_>>().contains(&Some("in_memory.slang")));
+
+	let other = session
+		.load_module_from_source_string(
+			"other",
+			"other.slang",
+			"float twice(float x) { return x * 2.0; }",
+		)
+		.unwrap();
+	assert!(other.unique_identity() != module.unique_identity());
+}
+
+#[test]
+fn session_clone() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+	let search_paths = [search_path.as_ptr()];
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths);
+
+	let session = global_session.create_session(&session_desc).unwrap();
+	let cloned = session.clone();
+
+	let compile = |session: &slang::Session| {
+		let module = session.load_module("test.slang").unwrap();
+		let entry_point = module.find_entry_point_by_name("main").unwrap();
+		let program = session
+			.create_composite_component_type(&[
+				module.downcast().clone(),
+				entry_point.downcast().clone(),
+			])
+			.unwrap();
+		program.link().unwrap().entry_point_code(0, 0).unwrap()
+	};
+
+	let code = compile(&session);
+	drop(session);
+	let cloned_code = compile(&cloned);
+	assert_eq!(code.as_slice(), cloned_code.as_slice());
+}
+
+#[test]
+fn structured_buffer_element_layout() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		struct Particle {
+			float3 position;
+			float mass;
+			float4 color;
+		}
+
+		StructuredBuffer<Particle> particles;
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			Particle particle = particles[thread_id.x];
+			output[thread_id.x] = particle.mass + particle.position.x + particle.color.w;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let particles = reflection.parameter_by_index(0).unwrap();
+	let buffer_layout = particles.type_layout().unwrap();
+
+	let element = buffer_layout.element_var_layout().unwrap();
+	let element_layout = element.type_layout().unwrap();
+	assert_eq!(element_layout.kind(), slang::TypeKind::Struct);
+	assert_eq!(
+		buffer_layout.element_type_layout().unwrap().name(),
+		Some("Particle")
+	);
+
+	let offsets = element_layout
+		.fields()
+		.map(|field| {
+			(
+				field.name().unwrap(),
+				field.offset(slang::ParameterCategory::Uniform),
+			)
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(offsets, [("position", 0), ("mass", 12), ("color", 16)]);
+	assert_eq!(element_layout.size(slang::ParameterCategory::Uniform), 32);
+}
+
+#[test]
+fn constant_buffer_container_layout() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		struct Globals {
+			float4 color;
+			float scale;
+		}
+
+		[[vk::binding(2)]]
+		ConstantBuffer<Globals> globals;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = globals.color * globals.scale;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let globals = reflection.parameter_by_index(0).unwrap();
+	let buffer_layout = globals.type_layout().unwrap();
+	assert_eq!(buffer_layout.kind(), slang::TypeKind::ConstantBuffer);
+
+	let container = buffer_layout.container_var_layout().unwrap();
+	assert_eq!(
+		container.category(),
+		Some(slang::ParameterCategory::DescriptorTableSlot)
+	);
+	let binding = globals.offset(slang::ParameterCategory::DescriptorTableSlot)
+		+ container.offset(slang::ParameterCategory::DescriptorTableSlot);
+	assert_eq!(binding, 2);
+
+	let element = buffer_layout.element_var_layout().unwrap();
+	let offsets = element
+		.type_layout()
+		.unwrap()
+		.fields()
+		.map(|field| field.offset(slang::ParameterCategory::Uniform))
+		.collect::<Vec<_>>();
+	assert_eq!(offsets, [0, 16]);
+}
+
+#[test]
+fn parameter_block_sub_objects() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		struct Material {
+			Texture2D albedo;
+			SamplerState albedo_sampler;
+			float4 tint;
+		}
+
+		ParameterBlock<Material> material;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			float4 color = material.albedo.SampleLevel(material.albedo_sampler, float2(0.5), 0.0);
+			output[thread_id.x] = color * material.tint;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let globals = reflection.global_params_type_layout().unwrap();
+	assert_eq!(globals.sub_object_range_count(), 1);
+
+	let binding_range = globals.sub_object_range_binding_range_index(0);
+	assert_eq!(binding_range, globals.field_binding_range_offset(0));
+	assert_eq!(
+		globals.binding_range_type(binding_range),
+		slang::BindingType::ParameterBlock
+	);
+
+	let block = globals.sub_object_range_type_layout(0).unwrap();
+	assert_eq!(block.kind(), slang::TypeKind::ParameterBlock);
+
+	let material = block.element_type_layout().unwrap();
+	let ranges = (0..material.binding_range_count())
+		.map(|i| material.binding_range_type(i))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		ranges,
+		[slang::BindingType::Texture, slang::BindingType::Sampler]
+	);
+}
+
+#[test]
+fn global_session_result() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	assert!(!global_session.find_profile("glsl_450").is_unknown());
+
+	assert!(slang::GlobalSession::new_without_core_module().is_ok());
+
+	// Success without a session pointer is an error rather than a null wrapper.
+	assert!(matches!(
+		slang::GlobalSession::from_result(0, std::ptr::null_mut()),
+		Err(slang::Error::Message(_))
+	));
+	assert!(matches!(
+		slang::GlobalSession::from_result(-1, std::ptr::null_mut()),
+		Err(slang::Error::Code(-1))
+	));
+}
+
+#[test]
+fn write_target_code_to_file() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	let path = std::env::temp_dir().join("slang-rs-write-target-code.spv");
+	program.write_target_code_to_file(0, &path).unwrap();
+
+	let written = std::fs::read(&path).unwrap();
+	std::fs::remove_file(&path).unwrap();
+	assert!(!written.is_empty());
+	assert_eq!(written, program.target_code(0).unwrap().as_slice());
+}
+
+#[test]
+fn blob_copy_from_slice() {
+	let blob = {
+		let data = String::from("transient source").into_bytes();
+		slang::Blob::copy_from_slice(&data)
+	};
+
+	let clone = blob.clone();
+	drop(blob);
+	assert_eq!(clone.as_slice(), b"transient source");
+	assert_eq!(clone.as_str().unwrap(), "transient source");
+}
+
+#[test]
+fn session_flags() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = spirv_targets(&global_session);
+	// Slang defines no flags besides kSessionFlags_None, so check that the bits reach the raw
+	// descriptor unchanged and create the session without any.
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.flags(1 << 3);
+	assert_eq!(session_desc.flags, 1 << 3);
+	let session_desc = session_desc.flags(0);
+	assert_eq!(session_desc.flags, 0);
+
+	// The core module is shared through the global session, so it is still available here.
+	let session = global_session.create_session(&session_desc).unwrap();
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = saturate(2.0);
+		}
+		"#,
+	);
+	assert!(program.entry_point_code(0, 0).is_ok());
+}
+
+#[test]
+fn downstream_args() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let options = slang::CompilerOptions::default().downstream_args("dxc", "-Wno-conversion");
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(&session, COMPUTE_SHADER);
+	assert!(program.entry_point_code(0, 0).is_ok());
+}
+
+#[test]
+fn target_count() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = [
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450")),
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Hlsl)
+			.profile(global_session.find_profile("sm_6_0")),
+	];
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+	let session = global_session.create_session(&session_desc).unwrap();
+	assert_eq!(session.target_count(), 2);
+	assert_eq!(session.clone().target_count(), 2);
+
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	for target in 0..session.target_count() {
+		assert!(program.layout(target as i64).is_ok());
+	}
+}
+
+#[test]
+fn include_from_memory() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let source = r#"
+		#include "helpers.hlsl"
+
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = scale(1.0);
+		}
+		"#;
+	let helpers = b"float scale(float x) { return x * 2.0; }";
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+	let search_paths = [search_path.as_ptr()];
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths)
+		.include_from_memory("helpers.hlsl", helpers);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	// The in-memory include and a module from the search paths load in the same session.
+	assert!(session.load_module("test.slang").is_ok());
+	let program = link_source(&session, source);
+	assert!(program.entry_point_code(0, 0).is_ok());
+
+	// The include is layered over a mutable file system too, which still receives the outputs.
+	let directory = slang::VirtualDirectory::default();
+	let options = slang::CompilerOptions::default().dump_intermediates(true);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options)
+		.mutable_file_system(directory.clone())
+		.include_from_memory("helpers.hlsl", helpers);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(&session, source);
+	assert!(program.entry_point_code(0, 0).is_ok());
+	assert!(!directory.paths().is_empty());
+	assert!(!directory.paths().contains(&"helpers.hlsl".to_string()));
+}
+
+#[test]
+fn used_parameter_locations() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> unused;
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = 1.0;
+		}
+		"#,
+	);
+
+	let metadata = program.entry_point_metadata(0, 0).unwrap();
+	let category = slang::ParameterCategory::DescriptorTableSlot;
+	assert_eq!(
+		metadata.is_parameter_location_used(category, 0, 0),
+		Some(false)
+	);
+	let layout = program.layout(0).unwrap();
+	assert_eq!(
+		metadata.used_parameter_locations(layout, category),
+		[(0, 1)]
+	);
+}
+
+#[test]
+fn blob_as_bytes() {
+	fn byte_count(data: impl AsRef<[u8]>) -> usize {
+		data.as_ref().len()
+	}
+
+	let blob = slang::Blob::copy_from_slice(b"bytes");
+	assert_eq!(byte_count(&blob), 5);
+	assert_eq!(&blob[..], b"bytes");
+	assert_eq!(blob.len(), blob.as_slice().len());
+}
+
+#[test]
+fn result_as_file_system() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	let file_system = program.result_as_file_system(0, 0).unwrap();
+	let entries = file_system.entries(".").unwrap();
+	assert!(!entries.is_empty());
+
+	for (path_type, name) in entries {
+		if path_type == slang::PathType::File {
+			assert!(!file_system.load_file(&name).unwrap().is_empty());
+		}
+	}
+}
+
+#[test]
+fn entry_point_stage() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(8, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = 1.0;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let entry_point = reflection.entry_point_by_index(0).unwrap();
+	assert_eq!(entry_point.stage(), slang::Stage::Compute);
+	assert_eq!(entry_point.name(), Some("main"));
+	assert_eq!(entry_point.compute_thread_group_size(), [8, 1, 1]);
+	assert!(entry_point.result_var_layout().is_some());
+}
+
+#[test]
+fn defines() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let defines = std::collections::HashMap::from([
+		("USE_A".to_string(), "1".to_string()),
+		("USE_B".to_string(), "1".to_string()),
+		("VALUE".to_string(), "4.0".to_string()),
+	]);
+	let options = slang::CompilerOptions::default().defines(&defines);
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		#if !defined(USE_A) || !defined(USE_B) || !defined(VALUE)
+		#error "missing define"
+		#endif
+
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = VALUE;
+		}
+		"#,
+	);
+	assert!(program.entry_point_code(0, 0).is_ok());
+}
+
+#[test]
+fn all_entry_points() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	session
+		.load_module_from_source_string(
+			"first",
+			"first.slang",
+			r#"
+			RWStructuredBuffer<float> output;
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void clear(uint3 thread_id: SV_DispatchThreadID) {
+				output[thread_id.x] = 0.0;
+			}
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void fill(uint3 thread_id: SV_DispatchThreadID) {
+				output[thread_id.x] = 1.0;
+			}
+			"#,
+		)
+		.unwrap();
+	session
+		.load_module_from_source_string(
+			"second",
+			"second.slang",
+			r#"
+			[shader("vertex")]
+			float4 main(float3 position: POSITION) : SV_Position {
+				return float4(position, 1.0);
+			}
+			"#,
+		)
+		.unwrap();
+
+	assert_eq!(session.loaded_module_count(), 2);
+	assert!(
+		session
+			.loaded_modules()
+			.any(|module| module.name() == Some("second"))
+	);
+	assert_eq!(session.all_entry_points().len(), 3);
+}
+
+#[test]
+fn global_params() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		uniform float scale;
+		uniform float4 color;
+		uniform float bias;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = color * scale + bias;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let layout = reflection.global_params_type_layout().unwrap();
+	let layout = layout.element_type_layout().unwrap_or(layout);
+	assert_eq!(layout.field_count(), 4);
+	assert!(layout.field_by_index(0).is_some());
+
+	let uniforms: Vec<_> = reflection
+		.global_params()
+		.into_iter()
+		.filter(|(_, category, _)| *category == slang::ParameterCategory::Uniform)
+		.collect();
+	assert_eq!(
+		uniforms,
+		[
+			("scale".to_string(), slang::ParameterCategory::Uniform, 0),
+			("color".to_string(), slang::ParameterCategory::Uniform, 16),
+			("bias".to_string(), slang::ParameterCategory::Uniform, 32),
+		]
+	);
+}
+
+#[test]
+fn load_module_with_diagnostics() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+	let search_paths = [search_path.as_ptr()];
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let (module, diagnostics) = session
+		.load_module_with_diagnostics("warning.slang")
+		.unwrap();
+	assert!(module.find_entry_point_by_name("main").is_some());
+
+	let diagnostics = diagnostics.unwrap();
+	assert!(diagnostics.as_str().unwrap().contains("warning"));
+}
+
+#[test]
+fn compile_timings() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let options = slang::CompilerOptions::default()
+		.report_perf_benchmark(true)
+		.report_downstream_time(true);
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(&session, COMPUTE_SHADER);
+	program.entry_point_code(0, 0).unwrap();
+
+	let timings = global_session.compile_timings();
+	assert!(timings.total > std::time::Duration::ZERO);
+}
+
+#[test]
+fn target_labels() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = [
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Hlsl)
+			.profile(global_session.find_profile("sm_6_0")),
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450")),
+	];
+	let labels = ["hlsl", "spirv"];
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.target_labels(&labels);
+	assert_eq!(session_desc.target_index_of("hlsl"), Some(0));
+	assert_eq!(session_desc.target_index_of("spirv"), Some(1));
+	assert_eq!(session_desc.target_index_of("metal"), None);
+
+	let session = global_session.create_session(&session_desc).unwrap();
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	let spirv = session_desc.target_index_of("spirv").unwrap();
+	let code = program.entry_point_code(0, spirv).unwrap();
+	assert_eq!(&code.as_slice()[..4], &[0x03, 0x02, 0x23, 0x07]);
+}
+
+#[test]
+fn mutable_file_system() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let file_system = slang::VirtualDirectory::default();
+	let options = slang::CompilerOptions::default().dump_intermediates(true);
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options)
+		.mutable_file_system(file_system.clone());
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(&session, COMPUTE_SHADER);
+	program.entry_point_code(0, 0).unwrap();
+
+	let paths = file_system.paths();
+	assert!(!paths.is_empty());
+
+	for path in paths {
+		assert!(!file_system.load_file(&path).unwrap().is_empty());
+	}
+}
+
+#[test]
+fn hashed_strings() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<int> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[0] = getStringHash("first");
+			output[1] = getStringHash("second");
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let strings: Vec<_> = reflection.hashed_strings().collect();
+	assert_eq!(strings.len(), reflection.hashed_string_count() as usize);
+	assert!(strings.contains(&"first"));
+	assert!(strings.contains(&"second"));
+	assert_ne!(
+		slang::reflection::compute_string_hash("first"),
+		slang::reflection::compute_string_hash("second")
+	);
+}
+
+#[test]
+fn type_full_name() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		StructuredBuffer<float4> input;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = input[thread_id.x];
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let ty = reflection.parameter_by_index(0).unwrap().ty().unwrap();
+	let element_type = ty.resource_result_type().unwrap();
+
+	let full_name = ty.full_name().unwrap();
+	let full_name = full_name.as_str().unwrap();
+	let element_name = element_type.full_name().unwrap();
+	assert!(full_name.starts_with("StructuredBuffer<"));
+	assert!(full_name.contains(element_name.as_str().unwrap()));
+	assert_eq!(ty.name(), Some("StructuredBuffer"));
+}
+
+#[test]
+fn debug_source_embedding() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	// Full debug information embeds the source text in the SPIR-V output.
+	let options = slang::CompilerOptions::default()
+		.emit_spirv_directly(true)
+		.debug_information(slang::DebugInfoLevel::Maximal);
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::SpirvAsm)
+		.profile(global_session.find_profile("glsl_450"))];
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		// embedded source marker
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = 1.0;
+		}
+		"#,
+	);
+
+	let code = program.entry_point_code(0, 0).unwrap();
+	let assembly = code.as_str().unwrap();
+	assert!(assembly.contains("OpSource"));
+	assert!(assembly.contains("embedded source marker"));
+}
+
+#[test]
+fn host_callable_kernel() {
+	// Layouts used by Slang's CPU target for compute entry points and structured buffers.
+	#[repr(C)]
+	struct ComputeVaryingInput {
+		start_group_id: [u32; 3],
+		end_group_id: [u32; 3],
+	}
+
+	#[repr(C)]
+	struct GlobalParams {
+		output: *mut f32,
+		output_count: usize,
+	}
+
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = [slang::TargetDesc::default().format(slang::CompileTarget::HostHostCallable)];
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = thread_id.x * 2.0;
+		}
+		"#,
+	);
+
+	let library = program.entry_point_host_callable(0, 0).unwrap();
+	let main = library.find_symbol_address_by_name("main").unwrap();
+	let main: extern "C" fn(*const ComputeVaryingInput, *mut std::ffi::c_void, *mut GlobalParams) =
+		unsafe { std::mem::transmute(main) };
+
+	let mut output = [0.0f32; 4];
+	let mut global_params = GlobalParams {
+		output: output.as_mut_ptr(),
+		output_count: output.len(),
+	};
+	let varying_input = ComputeVaryingInput {
+		start_group_id: [0, 0, 0],
+		end_group_id: [output.len() as u32, 1, 1],
+	};
+
+	main(&varying_input, std::ptr::null_mut(), &mut global_params);
+	assert_eq!(output, [0.0, 2.0, 4.0, 6.0]);
+}
+
+#[test]
+fn specialization_arg_count() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			interface IShape {
+				float area();
+			}
+
+			struct Square : IShape {
+				float side;
+				float area() { return side * side; }
+			}
+
+			uniform IShape shape;
+			RWStructuredBuffer<float> output;
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void main() {
+				output[0] = shape.area();
+			}
+			"#,
+		)
+		.unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	assert_eq!(program.specialization_param_count(), 1);
+
+	let reflection = program.layout(0).unwrap();
+	let shape = reflection
+		.parameters()
+		.find(|p| p.name() == Some("shape"))
+		.unwrap()
+		.type_layout()
+		.unwrap();
+	assert_eq!(shape.specialization_arg_count(), 1);
+	assert_eq!(shape.existential_value_count(), 1);
+
+	let square = reflection.find_type_by_name("Square").unwrap();
+	let specialized = program
+		.specialize(&[slang::SpecializationArg::from_type(square)])
+		.unwrap();
+	assert_eq!(specialized.specialization_param_count(), 0);
+}
+
+#[test]
+fn reflection_identity() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		struct Particle {
+			float3 position;
+			float3 velocity;
+		}
+
+		RWStructuredBuffer<Particle> particles;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			particles[thread_id.x].position += particles[thread_id.x].velocity;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let first = reflection.find_type_by_name("Particle").unwrap();
+	let second = reflection.find_type_by_name("Particle").unwrap();
+	assert!(first == second);
+
+	let mut types = std::collections::HashSet::new();
+	types.insert(first);
+	assert!(!types.insert(second));
+	assert!(types.insert(reflection.find_type_by_name("float").unwrap()));
+}
+
+#[test]
+fn per_target_matrix_layout() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	// Row-major globally, overridden to column-major for the SPIR-V target only.
+	let session_options = slang::CompilerOptions::default().matrix_layout_row(true);
+	let spirv_options = slang::CompilerOptions::default().matrix_layout_column(true);
+	let targets = [
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Dxil)
+			.profile(global_session.find_profile("sm_6_0")),
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450"))
+			.options(&spirv_options),
+	];
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&session_options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		uniform float3x4 transform;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = float4(mul(transform, float4(1.0)), 1.0);
+		}
+		"#,
+	);
+
+	let transform_layout = |target: i64| {
+		let reflection = program.layout(target).unwrap();
+		let globals = reflection.global_params_type_layout().unwrap();
+		let globals = globals.element_type_layout().unwrap_or(globals);
+		let index = globals.find_field_index_by_name("transform");
+		let transform = globals.field_by_index(index as u32).unwrap();
+		let layout = transform.type_layout().unwrap();
+		(
+			layout.matrix_layout_mode(),
+			layout.size(slang::ParameterCategory::Uniform),
+		)
+	};
+
+	let (dxil_mode, dxil_size) = transform_layout(0);
+	let (spirv_mode, spirv_size) = transform_layout(1);
+	assert_eq!(dxil_mode, slang::MatrixLayoutMode::RowMajor);
+	assert_eq!(spirv_mode, slang::MatrixLayoutMode::ColumnMajor);
+
+	// Three float4 rows versus four padded float3 columns.
+	assert_eq!(dxil_size, 48);
+	assert!(spirv_size > dxil_size);
+}
+
+#[test]
+fn target_presets() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let dxil = slang::TargetDesc::dxil_sm66(&global_session);
+	assert_eq!(dxil.format, slang::CompileTarget::Dxil);
+	let glsl = slang::TargetDesc::glsl_450(&global_session);
+	assert_eq!(glsl.format, slang::CompileTarget::Glsl);
+
+	let targets = [slang::TargetDesc::spirv_vulkan(&global_session)];
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		[shader("fragment")]
+		float4 main(float4 color: COLOR) : SV_Target {
+			return color;
+		}
+		"#,
+	);
+
+	let code = program.entry_point_code(0, 0).unwrap();
+	assert_eq!(&code.as_slice()[..4], &[0x03, 0x02, 0x23, 0x07]);
+}
+
+#[test]
+fn entry_points_for_stage() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("vertex")]
+		float4 vertex_main(float3 position: POSITION) : SV_Position {
+			return float4(position, 1.0);
+		}
+
+		[shader("fragment")]
+		float4 fragment_main() : SV_Target {
+			return float4(1.0);
+		}
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void compute_main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = 1.0;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	assert_eq!(reflection.entry_point_count(), 3);
+
+	let compute: Vec<_> = reflection
+		.entry_points_for_stage(slang::Stage::Compute)
+		.map(|e| e.name())
+		.collect();
+	assert_eq!(compute, [Some("compute_main")]);
+}
+
+#[test]
+fn all_target_codes() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let targets = [
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Dxil)
+			.profile(global_session.find_profile("sm_6_0")),
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450")),
+	];
+	let session_desc = slang::SessionDesc::default().targets(&targets);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	// Linked once, then used for both targets.
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	let codes = program
+		.all_target_codes(&session)
+		.into_iter()
+		.collect::<slang::Result<Vec<_>>>()
+		.unwrap();
+	assert_eq!(codes.len(), 2);
+	assert_eq!(&codes[0].as_slice()[..4], b"DXBC");
+	assert_eq!(&codes[1].as_slice()[..4], &[0x03, 0x02, 0x23, 0x07]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn composite_from_different_sessions() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let first_session = create_session(&global_session);
+	let second_session = create_session(&global_session);
+
+	let source = COMPUTE_SHADER;
+	let module = first_session
+		.load_module_from_source_string("test", "test.slang", source)
+		.unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let result = second_session.create_composite_component_type(&[
+		module.downcast().clone(),
+		entry_point.downcast().clone(),
+	]);
+	let Err(error) = result else {
+		panic!("composing components from another session should fail");
+	};
+	assert!(error.to_string().contains("same session"));
+}
+
+#[test]
+fn push_constants() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		struct Params {
+			float scale;
+		}
+
+		[[vk::push_constant]]
+		ConstantBuffer<Params> params;
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = params.scale;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let params = reflection.parameter_by_index(0).unwrap();
+	let output = reflection.parameter_by_index(1).unwrap();
+	assert!(params.is_push_constant());
+	assert!(!output.is_push_constant());
+}
+
+#[test]
+fn parameter_stage_mask() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		Texture2D<float4> shared_texture;
+		Texture2D<float4> fragment_texture;
+		SamplerState linear_sampler;
+
+		[shader("vertex")]
+		float4 vertex_main(float2 uv: TEXCOORD) : SV_Position {
+			return shared_texture.SampleLevel(linear_sampler, uv, 0.0);
+		}
+
+		[shader("fragment")]
+		float4 fragment_main(float2 uv: TEXCOORD) : SV_Target {
+			return shared_texture.Sample(linear_sampler, uv) + fragment_texture.Sample(linear_sampler, uv);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let vertex = 1 << slang::Stage::Vertex as u32;
+	let fragment = 1 << slang::Stage::Fragment as u32;
+
+	let shared_texture = reflection.parameter_by_index(0).unwrap();
+	let fragment_texture = reflection.parameter_by_index(1).unwrap();
+	assert_eq!(
+		program.parameter_stage_mask(shared_texture, 0).unwrap(),
+		vertex | fragment
+	);
+	assert_eq!(
+		program.parameter_stage_mask(fragment_texture, 0).unwrap(),
+		fragment
+	);
+}
+
+#[test]
+fn blob_into_vec() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	let code = program.entry_point_code(0, 0).unwrap();
+	let expected = code.as_slice().to_vec();
+	let bytes = code.into_vec();
+	assert_eq!(bytes, expected);
+	assert_eq!(&bytes[..4], &[0x03, 0x02, 0x23, 0x07]);
+}
+
+#[test]
+fn specialization_constants() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		[vk::constant_id(7)]
+		const int lane_count = 8;
+		RWStructuredBuffer<int> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = lane_count;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	assert_eq!(reflection.specialization_constant_count(), 1);
+
+	let constant = reflection.specialization_constants().next().unwrap();
+	assert_eq!(constant.name(), Some("lane_count"));
+	assert_eq!(constant.specialization_constant_id(), Some(7));
+	assert_eq!(constant.variable().unwrap().default_value_int(), Some(8));
+	assert_eq!(
+		constant.ty().unwrap().scalar_type(),
+		slang::ScalarType::Int32
+	);
+}
+
+#[test]
+fn code_with_diagnostics() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let source = include_str!("../shaders/warning.slang");
+
+	// The overlapping bindings are diagnosed during code generation, not when loading the module.
+	let session = create_session(&global_session);
+	let program = link_source(&session, source);
+	let (code, diagnostics) = program.entry_point_code_with_diagnostics(0, 0).unwrap();
+	assert!(!code.is_empty());
+	assert!(diagnostics.unwrap().as_str().unwrap().contains("overlaps"));
+
+	let session = create_session(&global_session);
+	let program = link_source(&session, source);
+	let (code, diagnostics) = program.target_code_with_diagnostics(0).unwrap();
+	assert!(!code.is_empty());
+	assert!(diagnostics.unwrap().as_str().unwrap().contains("overlaps"));
+}
+
+#[test]
+fn compile_target_file_extension() {
+	assert_eq!(slang::CompileTarget::Dxil.file_extension(), Some("dxil"));
+	assert_eq!(slang::CompileTarget::Spirv.file_extension(), Some("spv"));
+	assert_eq!(slang::CompileTarget::Glsl.file_extension(), Some("glsl"));
+	assert_eq!(
+		slang::CompileTarget::MetalLib.file_extension(),
+		Some("metallib")
+	);
+	assert_eq!(
+		slang::CompileTarget::HostHostCallable.file_extension(),
+		None
+	);
+}
+
+#[test]
+fn binary_module_cache() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	// Unique per run so concurrent test runs don't pick up each other's cached modules.
+	let cache_dir =
+		std::env::temp_dir().join(format!("slang-rs-module-cache-{}", std::process::id()));
+	std::fs::create_dir_all(&cache_dir).unwrap();
+	let cache_dir_str = std::ffi::CString::new(cache_dir.to_str().unwrap()).unwrap();
+	let shaders = std::ffi::CString::new("shaders").unwrap();
+
+	let options = slang::CompilerOptions::default().use_up_to_date_binary_module(true);
+	let targets = spirv_targets(&global_session);
+	let search_paths = [cache_dir_str.as_ptr(), shaders.as_ptr()];
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths)
+		.options(&options);
+
+	// The first session compiles from source and fills the cache.
+	let session = global_session.create_session(&session_desc).unwrap();
+	let module = session.load_module("test").unwrap();
+	let source_path = module.file_path().unwrap().to_string();
+	let cache_path = cache_dir.join("test.slang-module");
+	module.write_to_file(&cache_path).unwrap();
+
+	let serialized = module.serialize().unwrap();
+	assert!(session.is_binary_module_up_to_date(&source_path, &serialized));
+
+	// The second session finds the up to date binary module first.
+	let session = global_session.create_session(&session_desc).unwrap();
+	let module = session.load_module("test").unwrap();
+	assert!(module.file_path().unwrap().ends_with(".slang-module"));
+	assert!(module.find_entry_point_by_name("main").is_some());
+
+	// Paths are passed to Slang as UTF-8, so others are rejected rather than mangled.
+	#[cfg(unix)]
+	{
+		use std::os::unix::ffi::OsStrExt;
+		let invalid = cache_dir.join(std::ffi::OsStr::from_bytes(b"\xff.slang-module"));
+		assert!(module.write_to_file(invalid).is_err());
+	}
+
+	std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[test]
+fn parameter_directions() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		void accumulate(float value, out float result, inout int accum) {
+			result = value * 2.0;
+			accum += 1;
+		}
+
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			float result;
+			int accum = 0;
+			accumulate(1.0, result, accum);
+			output[thread_id.x] = result + accum;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let function = reflection.find_function_by_name("accumulate").unwrap();
+	let directions: Vec<_> = function
+		.parameters()
+		.map(|p| p.parameter_direction())
+		.collect();
+	assert_eq!(
+		directions,
+		[
+			slang::reflection::ParameterDirection::In,
+			slang::reflection::ParameterDirection::Out,
+			slang::reflection::ParameterDirection::InOut,
+		]
+	);
+}
+
+#[test]
+fn session_config() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let config = slang::SessionConfig {
+		targets: vec![slang::TargetConfig {
+			format: slang::CompileTarget::Spirv,
+			profile: "glsl_450".to_string(),
+		}],
+		search_paths: vec!["shaders".to_string()],
+		defines: vec![("SCALE".to_string(), "2.0".to_string())],
+		optimization: Some(slang::OptimizationLevel::High),
+	};
+
+	#[cfg(feature = "serde")]
+	let config = {
+		let json = serde_json::to_string(&config).unwrap();
+		let restored: slang::SessionConfig = serde_json::from_str(&json).unwrap();
+		assert_eq!(restored, config);
+		restored
+	};
+
+	let session = global_session.create_session_from_config(&config).unwrap();
+	assert_eq!(session.target_count(), 1);
+	assert!(session.load_module("test.slang").is_ok());
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = SCALE;
+		}
+		"#,
+	);
+	assert!(program.entry_point_code(0, 0).is_ok());
+}
+
+#[test]
+fn emit_spirv_paths() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let compile = |directly: bool| {
+		let options = slang::CompilerOptions::default()
+			.emit_spirv_directly(directly)
+			.skip_spirv_validation(false);
+		let targets = [slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450"))];
+		let session_desc = slang::SessionDesc::default()
+			.targets(&targets)
+			.options(&options);
+		let session = global_session.create_session(&session_desc).unwrap();
+
+		let program = link_source(
+			&session,
+			r#"
+			RWStructuredBuffer<float> output;
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void main(uint3 thread_id: SV_DispatchThreadID) {
+				output[thread_id.x] = sqrt(float(thread_id.x));
+			}
+			"#,
+		);
+		program.entry_point_code(0, 0).unwrap().into_vec()
+	};
+
+	// Both the direct and the via-GLSL paths must produce validated SPIR-V.
+	let direct = compile(true);
+	let via_glsl = compile(false);
+	assert_eq!(&direct[..4], &[0x03, 0x02, 0x23, 0x07]);
+	assert_eq!(&via_glsl[..4], &[0x03, 0x02, 0x23, 0x07]);
+	assert_ne!(direct, via_glsl);
+}
+
+#[test]
+fn spirv_validation_errors() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let options = slang::CompilerOptions::default()
+		.emit_spirv_directly(true)
+		.skip_spirv_validation(false);
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	// Integer addition on floats is rejected by the SPIR-V validator, not by Slang.
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			float value = float(thread_id.x);
+			output[thread_id.x] = spirv_asm {
+				result:$$float = OpIAdd $value $value
+			};
+		}
+		"#,
+	);
+
+	let Err(slang::Error::Blob(diagnostics)) = program.entry_point_code(0, 0) else {
+		panic!("invalid SPIR-V should fail validation");
+	};
+	assert!(diagnostics.as_str().unwrap().contains("IAdd"));
+}
+
+#[test]
+fn total_size_with_pending_data() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			interface IShape {
+				float area();
+			}
+
+			struct Rectangle : IShape {
+				float width;
+				float height;
+				float area() { return width * height; }
+			}
+
+			RWStructuredBuffer<float> output;
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void main(uniform IShape shape) {
+				output[0] = shape.area();
+			}
+			"#,
+		)
+		.unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+
+	let rectangle = program
+		.layout(0)
+		.unwrap()
+		.find_type_by_name("Rectangle")
+		.unwrap();
+	let specialized = program
+		.specialize(&[slang::SpecializationArg::from_type(rectangle)])
+		.unwrap()
+		.link()
+		.unwrap();
+
+	let reflection = specialized.layout(0).unwrap();
+	let shape = reflection
+		.entry_point_by_index(0)
+		.unwrap()
+		.parameter_by_index(0)
+		.unwrap()
+		.type_layout()
+		.unwrap();
+
+	let category = slang::ParameterCategory::Uniform;
+	let pending = shape.pending_data_type_layout().unwrap();
+	assert!(pending.size(category) > 0);
+	assert_eq!(
+		shape.total_size(category),
+		shape.size(category) + pending.size(category)
+	);
+}
+
+#[test]
+fn without_core_module() {
+	let source = COMPUTE_SHADER;
+
+	let core_module = slang::GlobalSession::new()
+		.unwrap()
+		.save_core_module(slang::ArchiveType::RiffDeflate)
+		.unwrap();
+
+	// Without a core module even trivial shaders fail to load, with an error rather than a crash.
+	let global_session = slang::GlobalSession::new_without_core_module().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+	assert!(
+		session
+			.load_module_from_source_string("test", "test.slang", source)
+			.is_err()
+	);
+
+	// Loading a previously saved core module makes the session fully usable.
+	let global_session = slang::GlobalSession::new_without_core_module().unwrap();
+	global_session.load_core_module(&core_module).unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(&session, source);
+	assert!(program.entry_point_code(0, 0).is_ok());
+}
+
+#[test]
+fn validate_compiler_options() {
+	let options = slang::CompilerOptions::default()
+		.optimization(slang::OptimizationLevel::High)
+		.macro_define("A", "1")
+		.macro_define("B", "2")
+		.matrix_layout_row(true);
+	assert!(options.validate().is_ok());
+
+	let options = slang::CompilerOptions::default()
+		.matrix_layout_row(true)
+		.matrix_layout_column(true);
+	assert!(options.validate().is_err());
+
+	let options = slang::CompilerOptions::default()
+		.optimization(slang::OptimizationLevel::None)
+		.optimization(slang::OptimizationLevel::Maximal);
+	let error = options.validate().unwrap_err();
+	assert!(error.to_string().contains("Optimization"));
+}
+
+#[test]
+fn imported_module_error_lines() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+	let search_paths = [search_path.as_ptr()];
+	let options =
+		slang::CompilerOptions::default().line_directive_mode(slang::LineDirectiveMode::Standard);
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let Err(slang::Error::Blob(diagnostics)) = session.load_module("broken_import") else {
+		panic!("the imported module has an error");
+	};
+
+	// The error is reported at its line in the imported file, not in the importing one.
+	let diagnostics = diagnostics.as_str().unwrap();
+	assert!(diagnostics.contains("broken_helper.slang(4)"));
+	assert!(diagnostics.contains("undefined_scale"));
+}
+
+#[test]
+fn constant_buffer_array_stride() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let program = link_source(
+		&session,
+		r#"
+		cbuffer Params {
+			float values[4];
+		}
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = values[thread_id.x];
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let params = reflection.parameter_by_index(0).unwrap();
+	let fields = params.type_layout().unwrap().element_type_layout().unwrap();
+	let values = fields.field_by_index(0).unwrap().type_layout().unwrap();
+
+	// Each array element is padded to 16 bytes in a constant buffer.
+	let category = slang::ParameterCategory::Uniform;
+	assert_eq!(values.element_stride(category), 16);
+	assert_eq!(values.element_type_layout().unwrap().size(category), 4);
+	assert_eq!(values.element_count(), Some(4));
+}
+
+#[test]
+fn entry_point_attributes() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		[__AttributeUsage(_AttributeTargets.Function)]
+		struct OutputVerticesAttribute { int count; };
+
+		struct VSOut { float4 position : SV_Position; };
+
+		[shader("geometry")]
+		[maxvertexcount(3)]
+		[OutputVertices(3)]
+		void main(triangle VSOut input[3], inout TriangleStream<VSOut> stream) {
+			for (int i = 0; i < 3; i++)
+				stream.Append(input[i]);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let entry_point = reflection.entry_point_by_index(0).unwrap();
+	assert_eq!(entry_point.stage(), slang::Stage::Geometry);
+	assert!(entry_point.function().is_some());
+
+	assert_eq!(entry_point.user_attributes().count(), 1);
+	let attribute = entry_point
+		.find_user_attribute_by_name(&global_session, "OutputVertices")
+		.unwrap();
+	assert_eq!(attribute.argument_count(), 1);
+	assert_eq!(attribute.argument_value_int(0), Some(3));
+}
+
+#[test]
+fn try_entry_points() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			[shader("vertex")]
+			float4 vs_main() : SV_Position { return 0; }
+
+			[shader("fragment")]
+			float4 fs_main() : SV_Target { return 1; }
+			"#,
+		)
+		.unwrap();
+
+	let entry_points = module
+		.try_entry_points()
+		.collect::<Result<Vec<_>, _>>()
+		.unwrap();
+	assert_eq!(entry_points.len(), 2);
+	assert!(module.try_entry_point_by_index(2).is_err());
+}
+
+#[test]
+fn output_semantics() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		struct Output {
+			float4 color : SV_Target0;
+			float4 normal : SV_Target1;
+		};
+
+		[shader("fragment")]
+		Output main() {
+			Output output;
+			output.color = float4(1, 0, 0, 1);
+			output.normal = float4(0, 0, 1, 0);
+			return output;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let entry_point = reflection.entry_point_by_index(0).unwrap();
+	let outputs = entry_point.output_semantics();
+	assert_eq!(outputs.len(), 2);
+	for ((name, index), expected) in outputs.into_iter().zip([0, 1]) {
+		assert!(name.eq_ignore_ascii_case("SV_Target"));
+		assert_eq!(index, expected);
+	}
+}
+
+#[test]
+fn preserve_parameters() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let bindings = |level: slang::OptimizationLevel| {
+		let options = slang::CompilerOptions::default()
+			.optimization(level)
+			.preserve_parameters(true);
+		let targets = [slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450"))];
+		let session_desc = slang::SessionDesc::default()
+			.targets(&targets)
+			.options(&options);
+		let session = global_session.create_session(&session_desc).unwrap();
+
+		let program = link_source(
+			&session,
+			r#"
+			Texture2D<float4> unused_texture;
+			RWStructuredBuffer<float> output;
+			StructuredBuffer<float> unused_input;
+			RWStructuredBuffer<float> scratch;
+
+			[shader("compute")]
+			[numthreads(64, 1, 1)]
+			void main(uint3 thread_id: SV_DispatchThreadID) {
+				output[thread_id.x] = scratch[thread_id.x] * 2.0;
+			}
+			"#,
+		);
+
+		program.entry_point_code(0, 0).unwrap();
+		program.layout(0).unwrap().parameter_bindings()
+	};
+
+	let debug = bindings(slang::OptimizationLevel::None);
+	let release = bindings(slang::OptimizationLevel::Maximal);
+	assert_eq!(debug.len(), 4);
+	assert_eq!(debug, release);
+}
+
+#[test]
+fn blob_copy_to_writer() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(64, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = float(thread_id.x);
+		}
+		"#,
+	);
+
+	let code = program.entry_point_code(0, 0).unwrap();
+	let mut out = Vec::new();
+	let written = code.copy_to_writer(&mut out).unwrap();
+	assert_eq!(written, code.as_slice().len());
+	assert_eq!(out, code.as_slice());
+}
+
+#[test]
+fn ir_blob_across_targets() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let spirv_session = create_session(&global_session);
+	let module = spirv_session
+		.load_module_from_source_string("portable", "portable.slang", COMPUTE_SHADER)
+		.unwrap();
+	let ir = module.serialize().unwrap();
+
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::Hlsl)
+		.profile(global_session.find_profile("sm_5_0"))];
+	let hlsl_session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+
+	let reloaded = hlsl_session
+		.load_module_from_ir_blob("portable", "portable.slang-module", &ir)
+		.unwrap();
+	let entry_point = reloaded.find_entry_point_by_name("main").unwrap();
+	let program = hlsl_session
+		.create_composite_component_type(&[
+			reloaded.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap()
+		.link()
+		.unwrap();
+	let code = program.entry_point_code(0, 0).unwrap();
+	assert!(code.as_str().unwrap().contains("numthreads"));
+
+	let garbage = slang::Blob::copy_from_slice(b"not a slang module");
+	assert!(
+		hlsl_session
+			.load_module_from_ir_blob("garbage", "garbage.slang-module", &garbage)
+			.is_err()
+	);
+}
+
+#[test]
+fn module_function_entry_points() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			RWStructuredBuffer<float> output;
+
+			float helper(float value) { return value * 2.0; }
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void main() {
+				output[0] = helper(1.0);
+			}
+
+			// An overload sharing the entry point's name must not be mistaken for it.
+			void main(float value) {
+				output[0] = value;
+			}
+			"#,
+		)
+		.unwrap();
+
+	let (entry_points, others): (Vec<_>, Vec<_>) = module
+		.module_reflection()
+		.children()
+		.filter_map(|decl| decl.as_function())
+		.partition(|function| module.is_entry_point(function));
+	assert_eq!(entry_points.len(), 1);
+	assert_eq!(entry_points[0].name(), Some("main"));
+	assert_eq!(entry_points[0].parameter_count(), 0);
+	assert_eq!(
+		module.entry_point_stage(entry_points[0]),
+		Some(slang::Stage::Compute)
+	);
+
+	let overload = others
+		.iter()
+		.find(|function| function.name() == Some("main"))
+		.unwrap();
+	assert_eq!(module.entry_point_stage(overload), None);
+}
+
+#[test]
+fn explicit_register_spaces() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::Hlsl)
+		.profile(global_session.find_profile("sm_5_1"))];
+	let session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+	let program = link_source(
+		&session,
+		r#"
+		Texture2D<float4> albedo : register(t0, space0);
+		Texture2D<float4> normals : register(t3, space2);
+		RWStructuredBuffer<float4> output : register(u1, space2);
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = albedo.Load(int3(0, 0, 0)) + normals.Load(int3(0, 0, 0));
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let expected = [
+		("albedo", slang::ParameterCategory::ShaderResource, 0, 0),
+		("normals", slang::ParameterCategory::ShaderResource, 2, 3),
+		("output", slang::ParameterCategory::UnorderedAccess, 2, 1),
+	];
+	for (parameter, (name, category, space, index)) in reflection.parameters().zip(expected) {
+		assert_eq!(parameter.name(), Some(name));
+		assert_eq!(parameter.category(), Some(category));
+		assert_eq!(parameter.binding_space_with_category(category), space);
+		assert_eq!(parameter.offset(category), index);
+	}
+}
+
+#[test]
+fn shader_program() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			RWStructuredBuffer<float> output;
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void clear() { output[0] = 0.0; }
+
+			[shader("compute")]
+			[numthreads(1, 1, 1)]
+			void fill() { output[0] = 1.0; }
+			"#,
+		)
+		.unwrap();
+
+	let program = session.program(&module, &["clear", "fill"]).unwrap();
+	assert_eq!(program.reflection().entry_point_count(), 2);
+	assert_eq!(program.reflection().parameter_count(), 1);
+
+	let fill = program.entry_point_code("fill", 0).unwrap();
+	assert!(!fill.as_slice().is_empty());
+	assert!(program.entry_point_code("missing", 0).is_err());
+	assert!(session.program(&module, &["missing"]).is_err());
+}
+
+#[test]
+fn find_parameter_by_name() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		[[vk::binding(0)]] Texture2D<float4> albedo;
+		[[vk::binding(3)]] SamplerState linear_sampler;
+		[[vk::binding(5)]] RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = albedo.SampleLevel(linear_sampler, float2(0, 0), 0);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let sampler = reflection.find_parameter_by_name("linear_sampler").unwrap();
+	assert_eq!(sampler.binding_index(), 3);
+	assert!(reflection.find_parameter_by_name("missing").is_none());
+}
+
+#[test]
+fn required_capabilities() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::Spirv)
+		.profile(global_session.find_profile("spirv_1_5"))];
+	let session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(32, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			output[thread_id.x] = WaveActiveSum(output[thread_id.x]);
+		}
+		"#,
+	);
+
+	let capabilities = program.required_capabilities(0).unwrap();
+	assert!(capabilities.iter().any(|c| c == "Shader"));
+	assert!(
+		capabilities
+			.iter()
+			.any(|c| c == "GroupNonUniformArithmetic")
+	);
+}
+
+#[test]
+fn scoped_compile_pool() {
+	let sources = (0..50)
+		.map(|i| {
+			format!(
+				r#"
+				RWStructuredBuffer<float> output;
+
+				[shader("compute")]
+				[numthreads(1, 1, 1)]
+				void main() {{
+					output[0] = {i}.0;
+				}}
+				"#
+			)
+		})
+		.collect::<Vec<_>>();
+
+	let sizes = slang::GlobalSession::scoped_compile_pool(&sources, 8, |global_session, source| {
+		let session = create_session(global_session);
+		let program = link_source(&session, source);
+		program
+			.entry_point_code(0, 0)
+			.map(|code| code.as_slice().len())
+	})
+	.unwrap();
+
+	assert_eq!(sizes.len(), 50);
+	assert!(
+		sizes
+			.into_iter()
+			.all(|size| size.is_ok_and(|size| size > 0))
+	);
+}
+
+#[test]
+fn no_hlsl_binding() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let hlsl = |options: slang::CompilerOptions| {
+		let targets = [slang::TargetDesc::default()
+			.format(slang::CompileTarget::Hlsl)
+			.profile(global_session.find_profile("sm_5_0"))];
+		let session_desc = slang::SessionDesc::default()
+			.targets(&targets)
+			.options(&options);
+		let session = global_session.create_session(&session_desc).unwrap();
+
+		let program = link_source(&session, COMPUTE_SHADER);
+
+		let code = program.entry_point_code(0, 0).unwrap();
+		code.as_str().unwrap().to_string()
+	};
+
+	assert!(hlsl(slang::CompilerOptions::default()).contains("register("));
+	assert!(!hlsl(slang::CompilerOptions::default().no_hlsl_binding(true)).contains("register("));
+}
+
+#[test]
+fn type_conformance_ids() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		interface IShape { float area(); }
+
+		struct Circle : IShape {
+			float radius;
+			float area() { return 3.14159 * radius * radius; }
+		}
+
+		struct Square : IShape {
+			float side;
+			float area() { return side * side; }
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let shape = reflection.find_type_by_name("IShape").unwrap();
+	let circle = reflection.find_type_by_name("Circle").unwrap();
+	let square = reflection.find_type_by_name("Square").unwrap();
+
+	let circle_id = session.type_conformance_id(circle, shape).unwrap();
+	let square_id = session.type_conformance_id(square, shape).unwrap();
+	assert_ne!(circle_id, square_id);
+	assert_eq!(
+		session.type_conformance_id(circle, shape).unwrap(),
+		circle_id
+	);
+
+	session
+		.create_type_conformance_component_type(circle, shape, Some(circle_id as i64))
+		.unwrap();
+}
+
+#[test]
+fn blob_concat() {
+	let prelude = slang::Blob::copy_from_slice(b"static const float SCALE = 2.0;\n");
+	let source = slang::Blob::copy_from_slice(b"float scale(float x) { return x * SCALE; }\n");
+
+	let combined = slang::Blob::concat(&[&prelude, &source]);
+	assert_eq!(
+		combined.as_slice().len(),
+		prelude.as_slice().len() + source.as_slice().len()
+	);
+	assert_eq!(
+		combined.as_str().unwrap(),
+		"static const float SCALE = 2.0;\nfloat scale(float x) { return x * SCALE; }\n"
+	);
+	assert!(slang::Blob::concat(&[]).as_slice().is_empty());
+}
+
+#[test]
+fn generic_value_parameters() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		struct Buffer<let N : int> {
+			float values[N];
+		}
+
+		ConstantBuffer<Buffer<16>> data;
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = data.values[15];
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let buffer = reflection.find_type_by_name("Buffer<16>").unwrap();
+	let generic = buffer.generic_container().unwrap();
+	assert_eq!(generic.type_parameter_count(), 0);
+	assert_eq!(generic.value_parameter_count(), 1);
+
+	let n = generic.value_parameter_by_index(0).unwrap();
+	assert_eq!(n.name(), Some("N"));
+	assert_eq!(generic.concrete_int_val(n), 16);
+}
+
+#[test]
+fn dump_ir() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let options = slang::CompilerOptions::default().dump_ir(true);
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(&session, COMPUTE_SHADER);
+
+	// Slang writes the IR dumps into the diagnostics of code generation, each under a
+	// `### <label>:` header.
+	let (_, diagnostics) = program.entry_point_code_with_diagnostics(0, 0).unwrap();
+	let diagnostics = diagnostics.unwrap();
+	let dump = diagnostics.as_str().unwrap();
+	assert!(dump.contains("### "));
+	assert!(dump.contains("main"));
+}
+
+#[test]
+fn entry_point_parameter_bindings() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::Hlsl)
+		.profile(global_session.find_profile("sm_5_0"))];
+	let session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+	let program = link_source(
+		&session,
+		r#"
+		struct Params { float scale; };
+
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uniform ConstantBuffer<Params> params, uniform Texture2D<float4> albedo) {
+			output[0] = albedo.Load(int3(0, 0, 0)) * params.scale;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let entry_point = reflection.entry_point_by_index(0).unwrap();
+	let bindings = entry_point.parameter_bindings();
+	assert_eq!(bindings.len(), 2);
+
+	assert_eq!(bindings[0].name, "params");
+	assert_eq!(
+		bindings[0].category,
+		slang::ParameterCategory::ConstantBuffer
+	);
+	assert_eq!((bindings[0].index, bindings[0].space), (0, 0));
+
+	assert_eq!(bindings[1].name, "albedo");
+	assert_eq!(
+		bindings[1].category,
+		slang::ParameterCategory::ShaderResource
+	);
+	assert_eq!((bindings[1].index, bindings[1].space), (0, 0));
+}
+
+#[test]
+fn blob_constructors() {
+	static SOURCE: &[u8] = b"float4 main() : SV_Target { return 1; }";
+
+	let from_static = slang::Blob::from_static(SOURCE);
+	assert_eq!(from_static.as_slice().as_ptr(), SOURCE.as_ptr());
+	assert_eq!(from_static.as_slice(), SOURCE);
+
+	let data = vec![1u8, 2, 3, 4];
+	let ptr = data.as_ptr();
+	let from_owned = slang::Blob::from_owned(data);
+	assert_eq!(from_owned.as_slice().as_ptr(), ptr);
+	assert_eq!(from_owned.as_slice(), [1, 2, 3, 4]);
+
+	let clone = from_owned.clone();
+	drop(from_owned);
+	assert_eq!(clone.as_slice(), [1, 2, 3, 4]);
+
+	assert_eq!(slang::Blob::from(SOURCE).as_slice(), SOURCE);
+	assert_eq!(slang::Blob::from(vec![5u8, 6]).as_slice(), [5, 6]);
+}
+
+#[test]
+fn file_system_released_with_session() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let file_system = std::rc::Rc::new(slang::VirtualDirectory::new(
+		std::collections::HashMap::from([(
+			"lib.slang".to_string(),
+			b"float one() { return 1.0; }".to_vec(),
+		)]),
+	));
+
+	for _ in 0..10_000 {
+		let session_desc = slang::SessionDesc::default().file_system(file_system.clone());
+		let session = global_session.create_session(&session_desc).unwrap();
+		drop(session_desc);
+		drop(session);
+	}
+
+	// Every session and its Slang-side file system wrapper have been freed.
+	assert_eq!(std::rc::Rc::strong_count(&file_system), 1);
+}
+
+#[test]
+fn load_module_from_source_with_diagnostics() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let (module, diagnostics) = session
+		.load_module_from_source_with_diagnostics(
+			"warning",
+			"warning.slang",
+			include_str!("../shaders/warning.slang"),
+		)
+		.unwrap();
+	assert!(module.find_entry_point_by_name("main").is_some());
+
+	let diagnostics = diagnostics.unwrap();
+	assert!(diagnostics.as_str().unwrap().contains("warning"));
+}
+
+#[test]
+fn module_struct_types() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			struct Light { float3 position; float3 color; float radius; };
+			struct Material { float4 albedo; float roughness; };
+
+			namespace scene {
+				struct Camera { float4x4 view; float4x4 projection; float3 position; float fov; };
+			}
+
+			float brightness(Light light) { return light.radius; }
+			"#,
+		)
+		.unwrap();
+
+	let types = module
+		.struct_types()
+		.into_iter()
+		.map(|ty| (ty.name().unwrap().to_string(), ty.field_count()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		types,
+		[
+			("Light".to_string(), 3),
+			("Material".to_string(), 2),
+			("Camera".to_string(), 4),
+		]
+	);
+}
+
+#[test]
+fn entry_point_uniform_size() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uniform float4 offset, uniform float4 scale) {
+			output[0] = output[0] * scale + offset;
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let entry_point = reflection.entry_point_by_index(0).unwrap();
+	assert!(entry_point.var_layout().is_some());
+
+	let mut layout = entry_point.type_layout().unwrap();
+	if layout.kind() == slang::TypeKind::ConstantBuffer {
+		layout = layout.element_type_layout().unwrap();
+	}
+	assert_eq!(layout.field_count(), 2);
+	assert_eq!(layout.size(slang::ParameterCategory::Uniform), 32);
+}
+
+#[test]
+fn default_constant_buffer_data() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		struct Params {
+			int count = 4;
+			uint flags = 7;
+			float scale = 0.5;
+			float4 color;
+		};
+
+		ConstantBuffer<Params> params;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = params.color * params.scale * float(params.count + params.flags);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let params = reflection.find_parameter_by_name("params").unwrap();
+	let layout = params.type_layout().unwrap().element_type_layout().unwrap();
+
+	let data = layout.default_data().unwrap();
+	assert_eq!(data.len(), 32);
+	assert_eq!(data[0..4], 4i32.to_le_bytes());
+	assert_eq!(data[4..8], 7u32.to_le_bytes());
+	assert_eq!(data[8..12], 0.5f32.to_le_bytes());
+	assert!(data[12..].iter().all(|&b| b == 0));
+
+	// No loose global uniforms, so the default constant buffer is empty.
+	assert!(
+		reflection
+			.default_constant_buffer_data()
+			.unwrap()
+			.is_empty()
+	);
+}
+
+#[test]
+fn virtual_files() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let files = std::collections::HashMap::from([
+		(
+			"lighting.slang".to_string(),
+			b"import constants;\nfloat attenuate(float d) { return SCALE / (d * d); }".to_vec(),
+		),
+		(
+			"constants.slang".to_string(),
+			b"static const float SCALE = 2.0;".to_vec(),
+		),
+	]);
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+	let search_paths = [search_path.as_ptr()];
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths)
+		.virtual_files(files.clone());
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	// Modules on disk are still found through the search paths.
+	assert!(session.load_module("test.slang").is_ok());
+
+	let program = link_source(
+		&session,
+		r#"
+		import lighting;
+
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = attenuate(2.0);
+		}
+		"#,
+	);
+	program.entry_point_code(0, 0).unwrap();
+
+	let directory = slang::VirtualDirectory::new(files);
+	assert!(directory.load_file("./constants.slang").is_ok());
+	assert!(directory.load_file("missing.slang").is_err());
+}
+
+#[test]
+fn descriptor_counts() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let program = link_source(
+		&session,
+		r#"
+		Texture2D<float4> albedo;
+		Texture2D<float4> normals;
+		SamplerState linear_sampler;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = albedo.SampleLevel(linear_sampler, float2(0, 0), 0)
+				+ normals.SampleLevel(linear_sampler, float2(0, 0), 0);
+		}
+		"#,
+	);
+
+	let counts = program.layout(0).unwrap().descriptor_counts();
+	assert_eq!(counts.len(), 3);
+	assert_eq!(counts[&slang::BindingType::Texture], 2);
+	assert_eq!(counts[&slang::BindingType::Sampler], 1);
+	assert_eq!(counts[&slang::BindingType::MutableRawBuffer], 1);
+
+	let program = link_source(
+		&session,
+		r#"
+		Texture2D<float4> textures[];
+		SamplerState linear_sampler;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uniform SamplerState point_sampler) {
+			output[0] = textures[0].SampleLevel(linear_sampler, float2(0, 0), 0)
+				+ textures[1].SampleLevel(point_sampler, float2(0, 0), 0);
+		}
+		"#,
+	);
+
+	// Entry point parameters are counted too, and unbounded arrays saturate.
+	let counts = program.layout(0).unwrap().descriptor_counts();
+	assert_eq!(counts[&slang::BindingType::Texture], u32::MAX);
+	assert_eq!(counts[&slang::BindingType::Sampler], 2);
+	assert_eq!(counts[&slang::BindingType::MutableRawBuffer], 1);
+}
+
+#[test]
+fn per_target_optimization() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let debug = slang::CompilerOptions::default().optimization(slang::OptimizationLevel::None);
+	let release = slang::CompilerOptions::default().optimization(slang::OptimizationLevel::Maximal);
+	let targets = [
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450"))
+			.options(&debug),
+		slang::TargetDesc::default()
+			.format(slang::CompileTarget::Spirv)
+			.profile(global_session.find_profile("glsl_450"))
+			.options(&release),
+	];
+	let session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		float square(float value) {
+			float result = value * value;
+			float unused = result * 3.0 + 1.0;
+			return result;
+		}
+
+		[shader("compute")]
+		[numthreads(64, 1, 1)]
+		void main(uint3 thread_id: SV_DispatchThreadID) {
+			float value = float(thread_id.x);
+			for (int i = 0; i < 4; i++)
+				value = square(value) * 0.5;
+			output[thread_id.x] = value;
+		}
+		"#,
+	);
+
+	let debug_code = program.entry_point_code(0, 0).unwrap();
+	let release_code = program.entry_point_code(0, 1).unwrap();
+	assert_ne!(debug_code.as_slice(), release_code.as_slice());
+	assert!(release_code.as_slice().len() <= debug_code.as_slice().len());
+}
+
+#[test]
+fn error_context() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let error = session
+		.load_module_from_source_string("broken", "broken.slang", "float broken( { }")
+		.context("loading module `broken`")
+		.with_context(|| "building material shaders".to_string())
+		.err()
+		.unwrap();
+
+	assert_eq!(error.to_string(), "building material shaders");
+
+	let chain = format!("{:?}", error);
+	assert!(chain.starts_with("building material shaders: loading module `broken`: "));
+	assert!(chain.contains("broken.slang"));
+
+	let source = std::error::Error::source(&error).unwrap();
+	assert_eq!(source.to_string(), "loading module `broken`");
+	assert!(std::error::Error::source(source).is_some());
+}
+
+#[test]
+fn reflect_type_from_source() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let snapshot = session
+		.reflect_type_from_source(
+			r#"
+			struct Light {
+				float3 position;
+				float radius;
+				float4 color;
+			};
+			"#,
+			"Light",
+		)
+		.unwrap();
+	drop(session);
+
+	assert_eq!(snapshot.name.as_deref(), Some("Light"));
+	assert_eq!(snapshot.kind, slang::TypeKind::Struct);
+	assert_eq!(snapshot.size, 32);
+
+	let fields: Vec<_> = snapshot
+		.fields
+		.iter()
+		.map(|field| (field.name.as_str(), field.offset, field.ty.size))
+		.collect();
+	assert_eq!(
+		fields,
+		[("position", 0, 12), ("radius", 12, 4), ("color", 16, 16)]
+	);
+}
+
+#[test]
+fn shader_entry_points() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+	let module = session
+		.load_module_from_source_string(
+			"test",
+			"test.slang",
+			r#"
+			struct VSOut { float4 position : SV_Position; };
+
+			[shader("vertex")]
+			VSOut vs_main(float3 position : POSITION) {
+				VSOut output;
+				output.position = float4(position, 1.0);
+				return output;
+			}
+
+			[shader("fragment")]
+			float4 fs_main(VSOut input) : SV_Target {
+				return input.position;
+			}
+			"#,
+		)
+		.unwrap();
+
+	let mut entry_points = module.shader_entry_points().unwrap();
+	entry_points.sort_by(|a, b| a.0.cmp(&b.0));
+	assert_eq!(
+		entry_points,
+		[
+			("fs_main".to_string(), slang::Stage::Fragment),
+			("vs_main".to_string(), slang::Stage::Vertex),
+		]
+	);
+}
+
+#[test]
+fn raw_compiler_options() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let options = slang::CompilerOptions::default()
+		.push_int_option(slang::CompilerOptionName::VulkanInvertY, 1, 0)
+		.push_string_option(slang::CompilerOptionName::MacroDefine, "SCALE", "3.0");
+	assert!(options.validate().is_ok());
+
+	let targets = spirv_targets(&global_session);
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.options(&options);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let program = link_source(
+		&session,
+		r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = SCALE;
+		}
+		"#,
+	);
+	assert!(
+		!program
+			.entry_point_code(0, 0)
+			.unwrap()
+			.as_slice()
+			.is_empty()
+	);
+}
+
+#[test]
+fn pointer_types() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let targets = [slang::TargetDesc::spirv_vulkan(&global_session)];
+	let session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+	let program = link_source(
+		&session,
+		r#"
+		struct Params {
+			Ptr<float4> data;
+			uint count;
+		};
+
+		ConstantBuffer<Params> params;
+		RWStructuredBuffer<float4> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main() {
+			output[0] = params.data[params.count - 1];
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+	let params = reflection.find_type_by_name("Params").unwrap();
+	let fields: Vec<_> = params.fields().collect();
+
+	let data = fields[0].ty().unwrap();
+	assert_eq!(data.kind(), slang::TypeKind::Pointer);
+	let pointee = data.pointee_type().unwrap();
+	assert_eq!(pointee.kind(), slang::TypeKind::Vector);
+	assert_eq!(pointee.element_count(), 4);
+	assert_eq!(
+		pointee.element_type().unwrap().scalar_type(),
+		slang::ScalarType::Float32
+	);
+
+	assert!(fields[1].ty().unwrap().pointee_type().is_none());
+}
+
+#[test]
+fn ray_tracing_entry_points() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let targets = [slang::TargetDesc::spirv_vulkan(&global_session)];
+	let session = global_session
+		.create_session(&slang::SessionDesc::default().targets(&targets))
+		.unwrap();
+	let program = link_source(
+		&session,
+		r#"
+		struct Payload { float4 color; };
+
+		RaytracingAccelerationStructure scene;
+		RWTexture2D<float4> output;
+
+		[shader("raygeneration")]
+		void raygen(uniform float4 clear_color) {
+			RayDesc ray;
+			ray.Origin = float3(0, 0, 0);
+			ray.Direction = float3(0, 0, 1);
+			ray.TMin = 0.0;
+			ray.TMax = 1000.0;
+
+			Payload payload = { clear_color };
+			TraceRay(scene, RAY_FLAG_NONE, 0xff, 0, 1, 0, ray, payload);
+			output[DispatchRaysIndex().xy] = payload.color;
+		}
+
+		[shader("closesthit")]
+		void closest_hit(
+			inout Payload payload,
+			BuiltInTriangleIntersectionAttributes attributes,
+			uniform float4 albedo,
+			uniform float roughness)
+		{
+			payload.color = albedo * (1.0 - roughness);
+		}
+		"#,
+	);
+
+	let reflection = program.layout(0).unwrap();
+
+	let raygen = reflection.find_entry_point_by_name("raygen").unwrap();
+	assert_eq!(raygen.stage(), slang::Stage::RayGeneration);
+	assert!(raygen.is_ray_tracing());
+	let raygen_layout = raygen.local_root_layout().unwrap();
+	assert_eq!(raygen_layout.field_count(), 1);
+	assert_eq!(raygen_layout.size(slang::ParameterCategory::Uniform), 16);
+
+	let closest_hit = reflection.find_entry_point_by_name("closest_hit").unwrap();
+	assert_eq!(closest_hit.stage(), slang::Stage::ClosestHit);
+	assert!(closest_hit.is_ray_tracing());
+	let hit_layout = closest_hit.local_root_layout().unwrap();
+	let fields: Vec<_> = hit_layout.fields().filter_map(|f| f.name()).collect();
+	assert!(fields.contains(&"albedo"));
+	assert!(fields.contains(&"roughness"));
+}